@@ -0,0 +1,36 @@
+use std::fmt::{Display, Formatter};
+use mlb_api::game::{Play, PlayEvent};
+use mlb_api::meta::HitTrajectory;
+
+#[derive(Copy, Clone)]
+pub struct BattedBall {
+    exit_velocity: f64,
+    launch_angle: f64,
+    distance: f64,
+}
+
+impl BattedBall {
+    /// From the play's final pitch, `None` if the ball wasn't tracked
+    pub fn from_play(play: &Play) -> Option<Self> {
+        let PlayEvent::Pitch { hit_data: Some(hit_data), .. } = play.play_events.last()? else { return None };
+        let statcast = hit_data.statcast.as_ref()?;
+        Some(Self {
+            exit_velocity: statcast.exit_velocity,
+            launch_angle: statcast.launch_angle,
+            distance: statcast.distance,
+        })
+    }
+}
+
+impl Display for BattedBall {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Self { exit_velocity, launch_angle, distance } = *self;
+        write!(f, "{exit_velocity:.1} mph, {launch_angle:.0}°, {distance:.0} ft")
+    }
+}
+
+/// The final pitch's batted ball trajectory, derived from the launch angle when the scorer hasn't set one
+pub fn trajectory(play: &Play) -> Option<HitTrajectory> {
+    let PlayEvent::Pitch { hit_data: Some(hit_data), .. } = play.play_events.last()? else { return None };
+    hit_data.hit_trajectory
+}
@@ -0,0 +1,111 @@
+use std::fmt::{Display, Formatter};
+
+use chrono::NaiveDate;
+use fxhash::FxHashMap;
+use mlb_api::game::{GameId, LiveFeedRequest, TeamWithGameData};
+use mlb_api::person::{Ballplayer, PersonId};
+use mlb_api::request::RequestURLBuilderExt;
+use mlb_api::stats::CountingStat;
+use mlb_api::team::TeamId;
+use mlb_api::{Handedness, TeamSide};
+use tokio::task::JoinSet;
+
+/// How many days back pitch counts are shown for
+pub const BULLPEN_DAYS: usize = 3;
+
+#[derive(Clone)]
+pub struct Bullpen {
+    relievers: Vec<Reliever>,
+}
+
+impl Bullpen {
+    /// `recent_games` are the games played in the [`BULLPEN_DAYS`] days before `date`, and an earlier game on `date` in a doubleheader.
+    ///
+    /// [`None`] if any of them couldn't be fetched, since a missing game would make a tired reliever look rested.
+    pub async fn new(
+        team: &TeamWithGameData,
+        our_id: TeamId,
+        all_players: &FxHashMap<PersonId, Ballplayer<()>>,
+        date: NaiveDate,
+        recent_games: &[(NaiveDate, GameId)],
+    ) -> Option<Self> {
+        let mut requests = JoinSet::new();
+        for &(game_date, game_id) in recent_games {
+            let Some(days_ago @ 0..=BULLPEN_DAYS) = usize::try_from((date - game_date).num_days()).ok() else { continue };
+            requests.spawn(async move { (days_ago, LiveFeedRequest::builder().id(game_id).build_and_get().await) });
+        }
+        // index 0 is earlier today
+        let mut pitches = FxHashMap::<PersonId, [CountingStat; BULLPEN_DAYS + 1]>::default();
+        while let Some(result) = requests.join_next().await {
+            let (days_ago, Ok(live_feed)) = result.ok()? else { return None };
+            let cheering_for = if live_feed.data.teams.home.id == our_id { TeamSide::Home } else { TeamSide::Away };
+            let team = live_feed.live.boxscore.teams.choose(cheering_for);
+            for id in &team.pitchers {
+                let thrown = team.players.get(id).and_then(|player| player.stats.pitching.number_of_pitches.ok()).unwrap_or_default();
+                pitches.entry(*id).or_default()[days_ago] += thrown;
+            }
+        }
+
+        let relievers = team.bullpen.iter().filter_map(|id| {
+            let player = team.players.get(id)?;
+            let [earlier_today, pitches @ ..] = pitches.get(id).copied().unwrap_or_default();
+            // pitching earlier today starts the streak, but not pitching yet today doesn't end it
+            let consecutive_days = usize::from(earlier_today > 0) + pitches.iter().take_while(|&&pitches| pitches > 0).count();
+            Some(Reliever {
+                name: player.boxscore_name.clone(),
+                handedness: all_players.get(id)?.pitch_hand,
+                earlier_today,
+                pitches,
+                consecutive_days,
+            })
+        }).collect();
+
+        Some(Self { relievers })
+    }
+}
+
+impl Display for Bullpen {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (idx, reliever) in self.relievers.iter().enumerate() {
+            if idx + 1 < self.relievers.len() {
+                writeln!(f, "{reliever}")?;
+            } else {
+                write!(f, "{reliever}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone)]
+struct Reliever {
+    name: String,
+    handedness: Handedness,
+    /// In the first game of a doubleheader
+    earlier_today: CountingStat,
+    /// Most recent day first
+    pitches: [CountingStat; BULLPEN_DAYS],
+    consecutive_days: usize,
+}
+
+impl Reliever {
+    fn is_likely_unavailable(&self) -> bool {
+        self.consecutive_days >= 2 || self.earlier_today >= 30 || self.pitches[0] >= 30
+    }
+}
+
+impl Display for Reliever {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Self { name, handedness, earlier_today, pitches, consecutive_days } = self;
+        let strikethrough = if self.is_likely_unavailable() { "~~" } else { "" };
+        let pitches = pitches.map(|pitches| if pitches == 0 { "-".to_owned() } else { pitches.to_string() });
+        write!(f, "`{handedness}` | {strikethrough}{name}{strikethrough} ({pitches})", pitches = pitches.join(" *|* "))?;
+        if *earlier_today > 0 {
+            write!(f, " (__{earlier_today} earlier today__)")?;
+        }
+        if *consecutive_days >= 2 {
+            write!(f, " (__{consecutive_days} straight days__)")?;
+        }
+        Ok(())
+    }
+}
@@ -11,19 +11,23 @@ pub struct Decisions {
     winner: Win,
     loser: Loss,
     save: Option<Save>,
+    holds: Vec<Hold>,
+    blown_saves: Vec<BlownSave>,
 }
 
 impl Decisions {
-    pub fn new(decisions: &mlb_api::game::Decisions, boxscore: &Boxscore) -> Result<Self> {
+    /// `None` if the game had no decisions (ex: a tie)
+    pub fn new(decisions: &mlb_api::game::Decisions, boxscore: &Boxscore, abbreviations: HomeAway<&str>) -> Result<Option<Self>> {
         fn get_person_with_team(boxscore: &Boxscore, id: PersonId) -> Result<(&PlayerWithGameData, &TeamWithGameData)> {
             let HomeAway { home, away } = boxscore.teams.as_ref().map(|team| team.players.get(&id).map(|player| (player, team)));
             home.or(away).context("Expected the winner to play in the game")
         }
 
-        let (winner, winners_team) = get_person_with_team(boxscore, decisions.winner.as_ref().context("Expected a winner")?.id)?;
-        let (loser, losers_team) = get_person_with_team(boxscore, decisions.loser.as_ref().context("Expected a loser")?.id)?;
+        let (Some(winner), Some(loser)) = (decisions.winner.as_ref(), decisions.loser.as_ref()) else { return Ok(None) };
+        let (winner, winners_team) = get_person_with_team(boxscore, winner.id)?;
+        let (loser, losers_team) = get_person_with_team(boxscore, loser.id)?;
 
-        Ok(Self {
+        Ok(Some(Self {
             winner: {
                 Win {
                     name: winner.boxscore_name.clone(),
@@ -49,10 +53,38 @@ impl Decisions {
                     }
                 })
             },
-        })
+            holds: {
+                pitchers_with(boxscore, abbreviations, |pitcher| pitcher.stats.pitching.holds.ok()).map(|(team_abbreviation, pitcher)| {
+                    Hold {
+                        team_abbreviation: team_abbreviation.to_owned(),
+                        name: pitcher.boxscore_name.clone(),
+                        holds: pitcher.season_stats.pitching.holds.unwrap_or_default(),
+                        line: PitchingLine::from_stats(&pitcher.stats.pitching, false, false),
+                    }
+                }).collect()
+            },
+            blown_saves: {
+                pitchers_with(boxscore, abbreviations, |pitcher| pitcher.stats.pitching.blown_saves.ok()).map(|(team_abbreviation, pitcher)| {
+                    BlownSave {
+                        team_abbreviation: team_abbreviation.to_owned(),
+                        name: pitcher.boxscore_name.clone(),
+                        blown_saves: pitcher.season_stats.pitching.blown_saves.unwrap_or_default(),
+                        line: PitchingLine::from_stats(&pitcher.stats.pitching, false, false),
+                    }
+                }).collect()
+            },
+        }))
     }
 }
 
+/// Pitchers from both teams (away first, in order of appearance) with a nonzero `stat` this game, with their team's abbreviation
+fn pitchers_with<'a>(boxscore: &'a Boxscore, abbreviations: HomeAway<&'a str>, stat: impl Fn(&PlayerWithGameData) -> Option<CountingStat>) -> impl Iterator<Item = (&'a str, &'a PlayerWithGameData)> {
+    [(abbreviations.away, &boxscore.teams.away), (abbreviations.home, &boxscore.teams.home)]
+        .into_iter()
+        .flat_map(|(abbreviation, team)| team.pitchers.iter().filter_map(move |id| Some((abbreviation, team.players.get(id)?))))
+        .filter(move |(_, pitcher)| stat(pitcher).is_some_and(|stat| stat > 0))
+}
+
 impl Display for Decisions {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "**Win**: {}", self.winner)?;
@@ -60,14 +92,16 @@ impl Display for Decisions {
         if let Some(save) = self.save.as_ref() {
             writeln!(f, "**Save**: {}", save)?;
         }
+        for hold in &self.holds {
+            writeln!(f, "**Hold**: {}", hold)?;
+        }
+        for blown_save in &self.blown_saves {
+            writeln!(f, "**Blown Save**: {}", blown_save)?;
+        }
         Ok(())
     }
 }
 
-fn pitching_line() -> PitchingLine {
-
-}
-
 #[derive(Clone)]
 struct Win {
     name: String,
@@ -109,3 +143,31 @@ impl Display for Save {
     }
 }
 
+
+#[derive(Clone)]
+struct Hold {
+    team_abbreviation: String,
+    name: String,
+    holds: CountingStat,
+    line: PitchingLine,
+}
+
+impl Display for Hold {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} (**{}**) | {}", self.team_abbreviation, self.name, self.holds, self.line)
+    }
+}
+
+#[derive(Clone)]
+struct BlownSave {
+    team_abbreviation: String,
+    name: String,
+    blown_saves: CountingStat,
+    line: PitchingLine,
+}
+
+impl Display for BlownSave {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} (**{}**) | {}", self.team_abbreviation, self.name, self.blown_saves, self.line)
+    }
+}
@@ -0,0 +1,43 @@
+use std::fmt::{Display, Formatter};
+use mlb_api::game::LiveFeedData;
+use uom::si::thermodynamic_temperature::degree_fahrenheit;
+use uom::si::velocity::mile_per_hour;
+
+#[derive(Clone)]
+pub struct GameInfo {
+    attendance: Option<u32>,
+    duration_minutes: Option<u32>,
+    temperature: f64,
+    condition: String,
+    wind_speed: f64,
+    wind_direction: String,
+}
+
+impl GameInfo {
+    pub fn new(data: &LiveFeedData) -> Self {
+        Self {
+            attendance: data.info.attendance,
+            duration_minutes: data.info.game_duration,
+            temperature: data.weather.temp.get::<degree_fahrenheit>(),
+            condition: data.weather.condition.clone(),
+            wind_speed: data.weather.wind_speed.get::<mile_per_hour>(),
+            wind_direction: data.weather.wind_direction.to_string(),
+        }
+    }
+}
+
+impl Display for GameInfo {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Self { attendance, duration_minutes, temperature, condition, wind_speed, wind_direction } = self;
+        let mut parts = Vec::new();
+        if let Some(attendance) = attendance {
+            parts.push(format!("Att: {attendance}"));
+        }
+        if let Some(duration_minutes) = duration_minutes {
+            parts.push(format!("T: {hours}:{minutes:02}", hours = duration_minutes / 60, minutes = duration_minutes % 60));
+        }
+        parts.push(format!("{temperature:.0}°F, {condition}"));
+        parts.push(format!("Wind: {wind_speed:.0} mph, {wind_direction}"));
+        write!(f, "{}", parts.join(" *|* "))
+    }
+}
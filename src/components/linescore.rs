@@ -1,4 +1,5 @@
-use mlb_api::game::Linescore;
+use anyhow::Result;
+use mlb_api::game::{Inning, InningHalf, Linescore, RHE};
 use std::fmt::{Display, Formatter, Write};
 use mlb_api::HomeAway;
 use mlb_api::team::Team;
@@ -31,9 +32,9 @@ impl LineScore {
                 &mut home_linescore,
                 "|{n: ^3}",
                 n = if inning.inning_record.home.was_inning_half_played {
-                    "-".to_owned()
-                } else {
                     inning.inning_record.home.runs.to_string()
+                } else {
+                    "-".to_owned()
                 }
             )?;
         }
@@ -67,6 +68,25 @@ impl LineScore {
             home_linescore,
         })
     }
+
+    /// As of the end of `half` of `inning`, since the feed's linescore may have already moved past it
+    pub fn through(linescore: &Linescore, teams: HomeAway<&Team<()>>, inning: Inning, half: InningHalf) -> Result<Self> {
+        let mut linescore = linescore.clone();
+        linescore.innings.retain(|record| *record.inning <= *inning);
+        if half == InningHalf::Top && let Some(last) = linescore.innings.last_mut() {
+            last.inning_record.home = RHE { runs: 0, hits: 0, errors: 0, left_on_base: 0, was_inning_half_played: false };
+        }
+        linescore.rhe_totals = linescore.innings.iter().fold(linescore.rhe_totals.map(|_| RHE { runs: 0, hits: 0, errors: 0, left_on_base: 0, was_inning_half_played: true }), |totals, record| {
+            totals.combine(record.inning_record, |total, inning| RHE {
+                runs: total.runs + inning.runs,
+                hits: total.hits + inning.hits,
+                errors: total.errors + inning.errors,
+                left_on_base: total.left_on_base + inning.left_on_base,
+                was_inning_half_played: true,
+            })
+        });
+        Self::new(&linescore, teams)
+    }
 }
 
 impl Display for LineScore {
@@ -0,0 +1,67 @@
+use std::fmt::{Display, Formatter};
+
+pub const SEASON_GAMES: u32 = 162;
+
+#[derive(Copy, Clone)]
+pub enum MagicNumber {
+    Magic(i64),
+    Elimination(i64),
+}
+
+impl MagicNumber {
+    pub fn magic(our_wins: u32, rival_losses: u32) -> Self {
+        Self::Magic(i64::from(SEASON_GAMES) + 1 - i64::from(our_wins) - i64::from(rival_losses))
+    }
+
+    pub fn elimination(rival_wins: u32, our_losses: u32) -> Self {
+        Self::Elimination(i64::from(SEASON_GAMES) + 1 - i64::from(rival_wins) - i64::from(our_losses))
+    }
+}
+
+impl Display for MagicNumber {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::Magic(n) if n <= 0 => write!(f, "Magic Number: **Clinched**"),
+            Self::Magic(n) => write!(f, "Magic Number: **{n}**"),
+            Self::Elimination(n) if n <= 0 => write!(f, "Elimination Number: **Eliminated**"),
+            Self::Elimination(n) => write!(f, "Elimination Number: **{n}**"),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct MagicNumbers {
+    division_name: String,
+    division: Option<MagicNumber>,
+    wild_card: Option<MagicNumber>,
+}
+
+impl MagicNumbers {
+    pub fn new(division_name: String, division: Option<MagicNumber>, wild_card: Option<MagicNumber>) -> Self {
+        Self {
+            division_name,
+            division,
+            wild_card,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.division.is_none() && self.wild_card.is_none()
+    }
+}
+
+impl Display for MagicNumbers {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Self { division_name, division, wild_card } = self;
+        if let Some(division) = division {
+            write!(f, "{division} ({division_name})")?;
+            if wild_card.is_some() {
+                writeln!(f)?;
+            }
+        }
+        if let Some(wild_card) = wild_card {
+            write!(f, "{wild_card} (Wild Card)")?;
+        }
+        Ok(())
+    }
+}
@@ -1,8 +1,14 @@
+pub mod batted_ball;
+pub mod bullpen;
 pub mod decisions;
+pub mod game_info;
 pub mod hitting;
 pub mod linescore;
+pub mod magic_number;
 pub mod next_game;
 pub mod pitching;
 pub mod record_against;
+pub mod series;
 pub mod standings;
 pub mod pitching_masterpiece;
+pub mod top_performers;
@@ -31,6 +31,36 @@ impl PitcherLineupEntry {
     }
 }
 
+/// A probable starter, or TBD when none has been announced or their stats can't be found
+#[derive(Clone)]
+pub enum ProbablePitcher {
+    Announced(PitcherLineupEntry),
+    Tbd { team_abbreviation: String },
+}
+
+impl ProbablePitcher {
+    pub fn tbd(team_abbreviation: &str) -> Self {
+        Self::Tbd { team_abbreviation: team_abbreviation.to_owned() }
+    }
+
+    #[must_use]
+    pub fn id(&self) -> Option<PersonId> {
+        match self {
+            Self::Announced(entry) => Some(entry.id()),
+            Self::Tbd { .. } => None,
+        }
+    }
+}
+
+impl Display for ProbablePitcher {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Announced(entry) => write!(f, "{entry}"),
+            Self::Tbd { team_abbreviation } => write!(f, "`?` | **{team_abbreviation}** TBD"),
+        }
+    }
+}
+
 impl Display for PitcherLineupEntry {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let Self { name, team_abbreviation, handedness, era, innings_pitched, id: _ } = self;
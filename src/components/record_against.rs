@@ -1,4 +1,4 @@
-use crate::util::statsapi::{BoldingDisplayKind, Score};
+use crate::util::statsapi::{BoldingDisplayKind, Outcome, Score};
 use core::fmt::{Display, Formatter};
 use mlb_api::TeamSide;
 
@@ -14,14 +14,18 @@ impl RecordAgainst {
         }
     }
 
-    pub fn win(&mut self) {
-        self.inner.away_runs += 1;
-        self.inner.who_scored = TeamSide::Away;
-    }
-
-    pub fn loss(&mut self) {
-        self.inner.home_runs += 1;
-        self.inner.who_scored = TeamSide::Home;
+    pub fn add(&mut self, outcome: Outcome) {
+        match outcome {
+            Outcome::Win => {
+                self.inner.away_runs += 1;
+                self.inner.who_scored = TeamSide::Away;
+            },
+            Outcome::Loss => {
+                self.inner.home_runs += 1;
+                self.inner.who_scored = TeamSide::Home;
+            },
+            Outcome::Tie => self.inner.ties += 1,
+        }
     }
 }
 
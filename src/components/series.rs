@@ -0,0 +1,106 @@
+use std::fmt::{Display, Formatter};
+
+use mlb_api::schedule::ScheduleGame;
+use crate::util::statsapi::Outcome;
+use mlb_api::{HomeAway, TeamSide};
+
+#[derive(Clone)]
+pub struct Series {
+    cheering_for: TeamSide,
+    our_abbreviation: String,
+    their_abbreviation: String,
+    /// Only for the postseason
+    round: Option<String>,
+    game_number: u32,
+    games_in_series: u32,
+    wins: u32,
+    losses: u32,
+    /// Whether `wins` and `losses` include this game
+    played: bool,
+}
+
+impl Series {
+    /// `previous_results` are our results against this opponent in the games right before this one, most recent last
+    pub fn new(game: &ScheduleGame<()>, cheering_for: TeamSide, our_abbreviation: &str, their_abbreviation: &str, previous_results: &[Outcome]) -> Option<Self> {
+        let series_data = game.series_data.as_ref()?;
+        let game_number = series_data.game_in_series_ordinal;
+        let previous_results = &previous_results[previous_results.len().saturating_sub((game_number as usize).saturating_sub(1))..];
+        let count = |outcome: Outcome| previous_results.iter().filter(|&&other| other == outcome).count() as u32;
+        Some(Self {
+            cheering_for,
+            our_abbreviation: our_abbreviation.to_owned(),
+            their_abbreviation: their_abbreviation.to_owned(),
+            round: game.game_type.is_postseason().then(|| game.game_type.to_string()),
+            game_number,
+            games_in_series: series_data.games_in_series,
+            wins: count(Outcome::Win),
+            losses: count(Outcome::Loss),
+            played: false,
+        })
+    }
+
+    pub fn add(&mut self, outcome: Outcome) {
+        match outcome {
+            Outcome::Win => self.wins += 1,
+            Outcome::Loss => self.losses += 1,
+            Outcome::Tie => {},
+        }
+        self.played = true;
+    }
+
+    pub fn is_postseason(&self) -> bool {
+        self.round.is_some()
+    }
+
+    pub fn game_number(&self) -> u32 {
+        self.game_number
+    }
+
+    pub fn games_in_series(&self) -> u32 {
+        self.games_in_series
+    }
+
+    fn wins_needed(&self) -> u32 {
+        self.games_in_series / 2 + 1
+    }
+
+    /// `Some(true)` if we won the series
+    pub fn winner(&self) -> Option<bool> {
+        if self.wins >= self.wins_needed() {
+            Some(true)
+        } else if self.losses >= self.wins_needed() {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    pub fn is_elimination_game(&self) -> bool {
+        !self.played && self.winner().is_none() && u32::max(self.wins, self.losses) + 1 == self.wins_needed()
+    }
+}
+
+impl Display for Series {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Self { our_abbreviation, their_abbreviation, round, game_number, games_in_series, wins, losses, .. } = self;
+        if let Some(round) = round {
+            write!(f, "{round} Game {game_number} *|* ")?;
+            match self.winner() {
+                Some(true) => return write!(f, "**{our_abbreviation} wins series {wins}-{losses}**"),
+                Some(false) => return write!(f, "**{their_abbreviation} wins series {losses}-{wins}**"),
+                None => {},
+            }
+        } else {
+            write!(f, "Game {game_number} of {games_in_series} {vs} {their_abbreviation} *|* ", vs = HomeAway::new("vs.", "@").choose(self.cheering_for))?;
+        }
+        match wins.cmp(losses) {
+            core::cmp::Ordering::Greater => write!(f, "Series: {our_abbreviation} leads {wins}-{losses}")?,
+            core::cmp::Ordering::Less => write!(f, "Series: {their_abbreviation} leads {losses}-{wins}")?,
+            core::cmp::Ordering::Equal => write!(f, "Series: Tied {wins}-{losses}")?,
+        }
+        if self.is_postseason() && self.is_elimination_game() {
+            write!(f, " *|* __Elimination Game__")?;
+        }
+        Ok(())
+    }
+}
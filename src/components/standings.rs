@@ -1,12 +1,37 @@
 use core::fmt::Display;
 use core::fmt::Formatter;
 use core::num::NonZeroUsize;
+use std::collections::VecDeque;
+
+use anyhow::{Context, Result};
+use chrono::{Datelike, NaiveDate};
+use fxhash::FxHashSet;
+use mlb_api::division::DivisionsRequest;
+use mlb_api::game::GameId;
+use mlb_api::league::LeagueId;
+use mlb_api::request::RequestURLBuilderExt;
+use mlb_api::schedule::{ScheduleGame, ScheduleRequest};
+use mlb_api::season::SeasonId;
+use mlb_api::sport::SportId;
+use mlb_api::standings::{Record, RecordSplitKind, StandingsRequest};
+use mlb_api::team::TeamId;
+use mlb_api::{HomeAway, TeamSide};
+use crate::components::magic_number::{MagicNumber, MagicNumbers};
+use crate::util::nth;
+use crate::util::statsapi::Outcome;
+
+pub const WILD_CARD_SPOTS: usize = 3;
 
 #[derive(Clone)]
 pub struct Standings {
     wins: u32,
     losses: u32,
-    streak: Option<(bool, NonZeroUsize)>,
+    ties: u32,
+    streak: Option<(Outcome, NonZeroUsize)>,
+    /// Most recent game last, only shown without the official standings (ex: spring training)
+    last_ten: VecDeque<Outcome>,
+    league: Option<LeagueStanding>,
+    splits: Splits,
 }
 
 impl Standings {
@@ -14,17 +39,29 @@ impl Standings {
         Self {
             wins: 0,
             losses: 0,
+            ties: 0,
             streak: None,
+            last_ten: VecDeque::with_capacity(10),
+            league: None,
+            splits: Splits::default(),
         }
     }
 
+    /// Uses the official standings instead of the locally counted record
+    pub fn set_league(&mut self, league: LeagueStanding) {
+        self.wins = league.wins;
+        self.losses = league.losses;
+        self.splits.extra_innings = league.extra_innings;
+        self.league = Some(league);
+    }
+
     pub fn streak_older_win(&mut self) -> bool {
         self.wins += 1;
-        if let Some((true, n)) = &mut self.streak {
+        if let Some((Outcome::Win, n)) = &mut self.streak {
             *n = n.saturating_add(1);
             true
         } else if self.streak.is_none() {
-            self.streak = Some((true, NonZeroUsize::MIN));
+            self.streak = Some((Outcome::Win, NonZeroUsize::MIN));
             true
         } else {
             false
@@ -33,47 +70,490 @@ impl Standings {
 
     pub fn streak_older_loss(&mut self) -> bool {
         self.losses += 1;
-        if let Some((false, n)) = &mut self.streak {
+        if let Some((Outcome::Loss, n)) = &mut self.streak {
             *n = n.saturating_add(1);
             true
         } else if self.streak.is_none() {
-            self.streak = Some((false, NonZeroUsize::MIN));
+            self.streak = Some((Outcome::Loss, NonZeroUsize::MIN));
             true
         } else {
             false
         }
     }
 
-    pub fn win(&mut self) {
-        self.wins += 1;
-        if let Some((true, n)) = &mut self.streak {
-            *n = n.saturating_add(1);
-        } else {
-            self.streak = Some((true, NonZeroUsize::MIN));
+    pub fn add(&mut self, outcome: Outcome) {
+        match outcome {
+            Outcome::Win => self.wins += 1,
+            Outcome::Loss => self.losses += 1,
+            Outcome::Tie => self.ties += 1,
+        }
+        match &mut self.streak {
+            Some((kind, n)) if *kind == outcome => *n = n.saturating_add(1),
+            streak => *streak = Some((outcome, NonZeroUsize::MIN)),
         }
     }
 
-    pub fn loss(&mut self) {
-        self.losses += 1;
-        if let Some((false, n)) = &mut self.streak {
-            *n = n.saturating_add(1);
-        } else {
-            self.streak = Some((false, NonZeroUsize::MIN));
+    /// Separate from [`Self::add`] since only games of the same type should count, that goes for the last ten and the official standings too
+    pub fn add_splits(&mut self, runs_scored: usize, runs_allowed: usize, side: TeamSide, extra_innings: bool) {
+        let outcome = Outcome::from_runs(runs_scored, runs_allowed);
+        let dropped = self.push_last_ten(outcome);
+        if let Some(league) = &mut self.league {
+            league.apply(outcome, dropped);
+        }
+        let splits = &mut self.splits;
+        // ties only show up in the overall record
+        let add = |record: &mut (u32, u32)| match outcome {
+            Outcome::Win => record.0 += 1,
+            Outcome::Loss => record.1 += 1,
+            Outcome::Tie => {},
+        };
+        splits.games += 1;
+        splits.runs_scored += runs_scored as u32;
+        splits.runs_allowed += runs_allowed as u32;
+        add(match side {
+            TeamSide::Home => &mut splits.home,
+            TeamSide::Away => &mut splits.road,
+        });
+        if runs_scored.abs_diff(runs_allowed) == 1 {
+            add(&mut splits.one_run);
         }
+        if extra_innings {
+            add(&mut splits.extra_innings);
+        }
+    }
+
+    pub fn splits<'a>(&'a self, shown: &'a [StandingsSplit]) -> SplitsDisplay<'a> {
+        SplitsDisplay(&self.splits, shown)
+    }
+
+    pub async fn update_rivals(&mut self) -> Result<()> {
+        if let Some(league) = &mut self.league {
+            league.update_rivals().await?;
+        }
+        Ok(())
+    }
+
+    pub fn magic_numbers(&self) -> Option<MagicNumbers> {
+        self.league.as_ref()?.magic_numbers()
+    }
+
+    /// Returns the game that fell out of the last ten
+    fn push_last_ten(&mut self, outcome: Outcome) -> Option<Outcome> {
+        let dropped = if self.last_ten.len() == 10 { self.last_ten.pop_front() } else { None };
+        self.last_ten.push_back(outcome);
+        dropped
     }
 }
 
 impl Display for Standings {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let (wins, losses) = (self.wins, self.losses);
-        if let Some((kind, streak)) = self.streak {
-            if kind {
-                write!(f, "**{wins}**-{losses} (__W{streak}__)")
-            } else {
-                write!(f, "{wins}-**{losses}** (__L{streak}__)")
+        let (wins, losses, ties) = (self.wins, self.losses, self.ties);
+        let ties = if ties > 0 { format!("-{ties}") } else { String::new() };
+        match self.streak {
+            Some((Outcome::Win, streak)) => write!(f, "**{wins}**-{losses}{ties} (__W{streak}__)")?,
+            Some((Outcome::Loss, streak)) => write!(f, "{wins}-**{losses}**{ties} (__L{streak}__)")?,
+            Some((Outcome::Tie, streak)) => write!(f, "{wins}-{losses}{ties} (__T{streak}__)")?,
+            None => write!(f, "{wins}-{losses}{ties} (__N/A__)")?,
+        }
+        if let Some(LeagueStanding { last_ten: (wins, losses), .. }) = &self.league {
+            write!(f, " *|* L10: {wins}-{losses}")?;
+        } else if !self.last_ten.is_empty() {
+            let count = |outcome: Outcome| self.last_ten.iter().filter(|&&other| other == outcome).count();
+            write!(f, " *|* L10: {}-{}", count(Outcome::Win), count(Outcome::Loss))?;
+            let last_ten_ties = count(Outcome::Tie);
+            if last_ten_ties > 0 {
+                write!(f, "-{last_ten_ties}")?;
+            }
+        }
+        if let Some(league) = &self.league {
+            write!(f, " *|* {league}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum StandingsSplit {
+    RunDifferential,
+    Pythagorean,
+    HomeRoad,
+    OneRun,
+    ExtraInnings,
+}
+
+#[derive(Clone, Default)]
+struct Splits {
+    games: u32,
+    runs_scored: u32,
+    runs_allowed: u32,
+    home: (u32, u32),
+    road: (u32, u32),
+    one_run: (u32, u32),
+    extra_innings: (u32, u32),
+}
+
+impl Splits {
+    const PYTHAGOREAN_EXPONENT: f64 = 1.83;
+
+    /// Wins and losses, ties don't count toward the Pythagorean record
+    fn decisions(&self) -> u32 {
+        self.home.0 + self.home.1 + self.road.0 + self.road.1
+    }
+
+    fn pythagorean_wins(&self) -> u32 {
+        let scored = f64::from(self.runs_scored).powf(Self::PYTHAGOREAN_EXPONENT);
+        let allowed = f64::from(self.runs_allowed).powf(Self::PYTHAGOREAN_EXPONENT);
+        if scored + allowed == 0.0 {
+            return 0
+        }
+        (f64::from(self.decisions()) * scored / (scored + allowed)).round() as u32
+    }
+}
+
+#[must_use]
+pub struct SplitsDisplay<'a>(&'a Splits, &'a [StandingsSplit]);
+
+impl SplitsDisplay<'_> {
+    pub fn is_empty(&self) -> bool {
+        self.0.games == 0 || self.1.is_empty()
+    }
+}
+
+impl Display for SplitsDisplay<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Self(splits, shown) = self;
+        for (idx, split) in shown.iter().enumerate() {
+            if idx > 0 {
+                write!(f, " *|* ")?;
             }
+            match split {
+                StandingsSplit::RunDifferential => {
+                    let differential = i64::from(splits.runs_scored) - i64::from(splits.runs_allowed);
+                    let sign = if differential > 0 { "+" } else { "" };
+                    write!(f, "RD: **{sign}{differential}** ({runs_scored} RS, {runs_allowed} RA)", runs_scored = splits.runs_scored, runs_allowed = splits.runs_allowed)?
+                },
+                StandingsSplit::Pythagorean => {
+                    let wins = splits.pythagorean_wins();
+                    write!(f, "Pythag: {wins}-{losses}", losses = splits.decisions() - wins)?
+                },
+                StandingsSplit::HomeRoad => write!(f, "Home: {}-{} *|* Road: {}-{}", splits.home.0, splits.home.1, splits.road.0, splits.road.1)?,
+                StandingsSplit::OneRun => write!(f, "1-Run: {}-{}", splits.one_run.0, splits.one_run.1)?,
+                StandingsSplit::ExtraInnings => write!(f, "Extras: {}-{}", splits.extra_innings.0, splits.extra_innings.1)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Games back, stored in half games. Negative means ahead.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GamesBack {
+    halves: i32,
+}
+
+impl GamesBack {
+    /// Parses the statsapi format (`-`, `1.5`, `+2.0`)
+    pub fn parse(s: &str) -> Self {
+        let (sign, s) = match s.strip_prefix('+') {
+            Some(s) => (-1, s),
+            None => (1, s),
+        };
+        let (whole, half) = s.split_once('.').unwrap_or((s, "0"));
+        let halves = whole.parse::<i32>().unwrap_or(0) * 2 + i32::from(half.starts_with('5'));
+        Self { halves: sign * halves }
+    }
+}
+
+impl Display for GamesBack {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.halves == 0 {
+            return write!(f, "-")
+        }
+        let sign = if self.halves < 0 { "+" } else { "" };
+        let halves = self.halves.unsigned_abs();
+        let fraction = if halves % 2 == 1 { ".5" } else { "" };
+        write!(f, "{sign}{whole}{fraction}", whole = halves / 2)
+    }
+}
+
+#[derive(Clone)]
+pub struct LeagueStanding {
+    wins: u32,
+    losses: u32,
+    /// Wins and losses in the last ten games
+    last_ten: (u32, u32),
+    /// Wins and losses in extra inning games
+    extra_innings: (u32, u32),
+    date: NaiveDate,
+    division: String,
+    division_rank: usize,
+    games_back: GamesBack,
+    wild_card: Option<(usize, GamesBack)>,
+    /// Second place if we lead the division, otherwise the leader
+    division_rival: Option<Rival>,
+    /// The first team out if we're in a wild card spot, otherwise the last team in
+    wild_card_rival: Option<Rival>,
+}
+
+impl LeagueStanding {
+    /// `None` if the team isn't in the standings (ex: spring training)
+    pub async fn new(team_id: TeamId, league_id: LeagueId, season: SeasonId, date: NaiveDate) -> Result<Option<Self>> {
+        let divisions = StandingsRequest::<()>::builder()
+            .league_id(league_id)
+            .season(season)
+            .date(date)
+            .build_and_get().await?
+            .divisions;
+
+        let Some(division) = divisions.iter().find(|division| division.team_records.iter().any(|record| record.team.id == team_id)) else { return Ok(None) };
+        let record = division.team_records.iter().find(|record| record.team.id == team_id).context("Expected team to be in its division")?;
+        let Some(division_rank) = record.divisional_rank else { return Ok(None) };
+        let division_name = DivisionsRequest::builder()
+            .division_id(division.division_id)
+            .build_and_get().await?
+            .divisions.into_iter().next()
+            .map_or_else(String::new, |division| division.short_name);
+
+        // the response has no wild card rank, so it's the league rank among the teams not leading their division
+        let wild_card_ranks = {
+            let mut contenders = divisions.iter()
+                .flat_map(|division| &division.team_records)
+                .filter(|record| record.divisional_rank != Some(1))
+                .collect::<Vec<_>>();
+            contenders.sort_by_key(|record| record.league_rank.unwrap_or(usize::MAX));
+            contenders.into_iter().enumerate().map(|(idx, record)| (record.team.id, idx + 1)).collect::<Vec<_>>()
+        };
+        let wild_card_rank = |id: TeamId| wild_card_ranks.iter().find(|(other, _)| *other == id).map(|&(_, rank)| rank);
+
+        let division_rival_rank = if division_rank == 1 { 2 } else { 1 };
+        let division_rival = match division.team_records.iter().find(|record| record.divisional_rank == Some(division_rival_rank)) {
+            Some(rival) => Some(Rival::new(rival.team.id, wins_losses(rival.record), date).await?),
+            None => None,
+        };
+        let wild_card = wild_card_rank(team_id);
+        let wild_card_rival = match wild_card.and_then(|rank| {
+            let rival_rank = if rank <= WILD_CARD_SPOTS { WILD_CARD_SPOTS + 1 } else { WILD_CARD_SPOTS };
+            divisions.iter().flat_map(|division| &division.team_records).find(|record| wild_card_rank(record.team.id) == Some(rival_rank))
+        }) {
+            Some(rival) => Some(Rival::new(rival.team.id, wins_losses(rival.record), date).await?),
+            None => None,
+        };
+
+        let split = |kind: RecordSplitKind| record.splits.record_splits.iter()
+            .find(|split| split.kind == kind)
+            .map_or((0, 0), |split| wins_losses(split.record));
+        let (wins, losses) = wins_losses(record.record);
+
+        Ok(Some(Self {
+            wins,
+            losses,
+            last_ten: split(RecordSplitKind::LastTen),
+            extra_innings: split(RecordSplitKind::ExtraInnings),
+            date,
+            division: division_name,
+            division_rank,
+            // the crate's games back keeps its fields to itself, so it goes through the statsapi format again
+            games_back: GamesBack::parse(&record.games_back.to_string()),
+            wild_card: wild_card.map(|rank| (rank, GamesBack::parse(&record.wild_card_games_back.to_string()))),
+            division_rival,
+            wild_card_rival,
+        }))
+    }
+
+    /// Our own result, other teams' games are applied in [`Self::update_rivals`].
+    ///
+    /// `dropped` is the game that falls out of the last ten, if there were ten already.
+    fn apply(&mut self, outcome: Outcome, dropped: Option<Outcome>) {
+        let change = match outcome {
+            Outcome::Win => -1,
+            Outcome::Loss => 1,
+            Outcome::Tie => return,
+        };
+        match dropped {
+            Some(Outcome::Win) => self.last_ten.0 = self.last_ten.0.saturating_sub(1),
+            Some(Outcome::Loss) => self.last_ten.1 = self.last_ten.1.saturating_sub(1),
+            Some(Outcome::Tie) | None => {},
+        }
+        if outcome == Outcome::Win {
+            self.wins += 1;
+            self.last_ten.0 += 1;
         } else {
-            write!(f, "{wins}-{losses} (__N/A__)")
+            self.losses += 1;
+            self.last_ten.1 += 1;
+        }
+        if self.division_rank > 1 {
+            self.games_back.halves = (self.games_back.halves + change).max(0);
+        }
+        if let Some((_, games_back)) = &mut self.wild_card {
+            games_back.halves += change;
+        }
+    }
+
+    /// Counts the rivals' games that finished since the standings were fetched
+    pub async fn update_rivals(&mut self) -> Result<()> {
+        for rival in self.division_rival.iter_mut().chain(self.wild_card_rival.iter_mut()) {
+            rival.update(self.date).await?;
         }
+        Ok(())
     }
-}
\ No newline at end of file
+
+    /// Only shown once the race gets close, from September on
+    pub fn magic_numbers(&self) -> Option<MagicNumbers> {
+        if self.date.month() < 9 {
+            return None
+        }
+        let division = self.division_rival.as_ref().map(|rival| if self.division_rank == 1 {
+            MagicNumber::magic(self.wins, rival.losses)
+        } else {
+            MagicNumber::elimination(rival.wins, self.losses)
+        });
+        let wild_card = self.wild_card.zip(self.wild_card_rival.as_ref()).map(|((rank, _), rival)| if rank <= WILD_CARD_SPOTS {
+            MagicNumber::magic(self.wins, rival.losses)
+        } else {
+            MagicNumber::elimination(rival.wins, self.losses)
+        });
+        Some(MagicNumbers::new(self.division.clone(), division, wild_card))
+    }
+}
+
+impl Display for LeagueStanding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Self { division, division_rank, games_back, wild_card, .. } = self;
+        write!(f, "**{rank}** in {division} ({games_back} GB)", rank = nth(*division_rank))?;
+        if let Some((wild_card_rank, wild_card_games_back)) = wild_card {
+            write!(f, " *|* WC: **{rank}** ({wild_card_games_back})", rank = nth(*wild_card_rank))?;
+        }
+        Ok(())
+    }
+}
+
+/// The crate keeps a record's wins and losses private, so they're recovered from its winning percentage
+fn wins_losses(record: Record) -> (u32, u32) {
+    let games = record.games_played();
+    let wins = if games == 0 { 0 } else { (f64::from(record.pct()) * games as f64).round() as usize };
+    (wins as u32, (games - wins) as u32)
+}
+
+#[derive(Clone)]
+struct Rival {
+    id: TeamId,
+    wins: u32,
+    losses: u32,
+    /// Games already reflected in `wins` and `losses`
+    counted_games: FxHashSet<GameId>,
+}
+
+impl Rival {
+    async fn new(id: TeamId, (wins, losses): (u32, u32), date: NaiveDate) -> Result<Self> {
+        let mut rival = Self {
+            id,
+            wins,
+            losses,
+            counted_games: FxHashSet::default(),
+        };
+        // games that were already final when the standings were fetched are in the standings
+        for game in rival.finished_games(date).await? {
+            rival.counted_games.insert(game.game_id);
+        }
+        Ok(rival)
+    }
+
+    async fn finished_games(&self, date: NaiveDate) -> Result<Vec<ScheduleGame<()>>> {
+        Ok(ScheduleRequest::<()>::builder()
+            .sport_id(SportId::MLB)
+            .date(date)
+            .team_id(self.id)
+            .build_and_get().await?
+            .dates.into_iter()
+            .flat_map(|date| date.games)
+            .filter(|game| game.status.abstract_game_code.is_finished())
+            .collect())
+    }
+
+    async fn update(&mut self, date: NaiveDate) -> Result<()> {
+        for game in self.finished_games(date).await? {
+            let (Some(home_score), Some(away_score)) = (&game.teams.home.score, &game.teams.away.score) else { continue };
+            let runs = HomeAway::new(home_score.runs_scored as usize, away_score.runs_scored as usize);
+            let side = if self.id == game.teams.home.team.id { TeamSide::Home } else { TeamSide::Away };
+            self.add_result(game.game_id, runs, side);
+        }
+        Ok(())
+    }
+
+    /// A game that was already counted is ignored, since every refresh sees the whole day's games
+    fn add_result(&mut self, game_id: GameId, runs: HomeAway<usize>, side: TeamSide) {
+        if !self.counted_games.insert(game_id) {
+            return
+        }
+        match Outcome::from_runs(runs.choose(side), runs.choose(!side)) {
+            Outcome::Win => self.wins += 1,
+            Outcome::Loss => self.losses += 1,
+            Outcome::Tie => {},
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Leading the division at 90-60 with the last ten going L, W, W, L, W, W, L, W, W, L
+    fn leading_division(rival_wins: u32, rival_losses: u32) -> Standings {
+        let mut standings = Standings::new();
+        for won in [false, true, true, false, true, true, false, true, true, false] {
+            let (scored, allowed) = if won { (5, 3) } else { (3, 5) };
+            standings.add_splits(scored, allowed, TeamSide::Home, false);
+        }
+        standings.set_league(LeagueStanding {
+            wins: 90,
+            losses: 60,
+            last_ten: (6, 4),
+            extra_innings: (0, 0),
+            date: NaiveDate::from_ymd_opt(2025, 9, 20).unwrap(),
+            division: "AL East".to_owned(),
+            division_rank: 1,
+            games_back: GamesBack::parse("-"),
+            wild_card: None,
+            division_rival: Some(Rival {
+                id: TeamId::new(2),
+                wins: rival_wins,
+                losses: rival_losses,
+                counted_games: FxHashSet::default(),
+            }),
+            wild_card_rival: None,
+        });
+        standings
+    }
+
+    fn division_rival(standings: &mut Standings) -> &mut Rival {
+        standings.league.as_mut().unwrap().division_rival.as_mut().unwrap()
+    }
+
+    #[test]
+    fn rival_loss_after_game_end() {
+        let mut standings = leading_division(85, 65);
+        assert_eq!(standings.magic_numbers().unwrap().to_string(), "Magic Number: **8** (AL East)");
+
+        standings.add(Outcome::Win);
+        standings.add_splits(4, 2, TeamSide::Home, false);
+        division_rival(&mut standings).add_result(GameId::new(1), HomeAway::new(2, 6), TeamSide::Home);
+        assert_eq!(standings.magic_numbers().unwrap().to_string(), "Magic Number: **6** (AL East)");
+        // the loss ten games ago drops off
+        assert!(standings.to_string().contains("L10: 7-3"));
+
+        // the next refresh sees the same game again
+        division_rival(&mut standings).add_result(GameId::new(1), HomeAway::new(2, 6), TeamSide::Home);
+        assert_eq!(standings.magic_numbers().unwrap().to_string(), "Magic Number: **6** (AL East)");
+    }
+
+    #[test]
+    fn rival_win_after_game_end() {
+        let mut standings = leading_division(85, 65);
+
+        standings.add(Outcome::Loss);
+        standings.add_splits(2, 4, TeamSide::Away, false);
+        division_rival(&mut standings).add_result(GameId::new(1), HomeAway::new(6, 2), TeamSide::Home);
+        assert_eq!(standings.magic_numbers().unwrap().to_string(), "Magic Number: **8** (AL East)");
+        assert!(standings.to_string().contains("L10: 6-4"));
+    }
+}
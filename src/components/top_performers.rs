@@ -0,0 +1,95 @@
+use std::fmt::{Display, Formatter};
+use mlb_api::game::{PlayerWithGameData, TeamWithGameData};
+use mlb_api::stats::CountingStat;
+use mlb_api::{HomeAway, TeamSide};
+
+#[derive(Clone)]
+pub struct TopPerformers {
+    /// Team abbreviation and its standout hitters, away first
+    teams: Vec<(String, Vec<Performance>)>,
+}
+
+impl TopPerformers {
+    /// `cheering_for` is `None` to include both teams
+    pub fn new(teams: HomeAway<&TeamWithGameData>, abbreviations: HomeAway<&str>, cheering_for: Option<TeamSide>) -> Option<Self> {
+        let teams = [TeamSide::Away, TeamSide::Home]
+            .into_iter()
+            .filter(|&side| cheering_for.is_none_or(|cheering_for| cheering_for == side))
+            .map(|side| {
+                let team = teams.choose(side);
+                let performances = team.batters.iter().filter_map(|id| team.players.get(id)).map(Performance::new).filter(Performance::is_standout).collect::<Vec<_>>();
+                (abbreviations.choose(side).to_owned(), performances)
+            })
+            .filter(|(_, performances)| !performances.is_empty())
+            .collect::<Vec<_>>();
+        (!teams.is_empty()).then_some(Self { teams })
+    }
+}
+
+impl Display for TopPerformers {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let show_abbreviation = self.teams.len() > 1;
+        for (idx, (abbreviation, performances)) in self.teams.iter().enumerate() {
+            if show_abbreviation {
+                writeln!(f, "**{abbreviation}**")?;
+            }
+            for (jdx, performance) in performances.iter().enumerate() {
+                if idx + 1 < self.teams.len() || jdx + 1 < performances.len() {
+                    writeln!(f, "{performance}")?;
+                } else {
+                    write!(f, "{performance}")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A hitter's game line, ex: "Guerrero Jr. 3-4, 2B, HR, 3 RBI"
+#[derive(Clone)]
+pub struct Performance {
+    name: String,
+    hits: CountingStat,
+    at_bats: CountingStat,
+    doubles: CountingStat,
+    triples: CountingStat,
+    home_runs: CountingStat,
+    rbi: CountingStat,
+    stolen_bases: CountingStat,
+}
+
+impl Performance {
+    pub fn new(player: &PlayerWithGameData) -> Self {
+        let stats = &player.stats.hitting;
+        Self {
+            name: player.boxscore_name.clone(),
+            hits: stats.hits.unwrap_or_default(),
+            at_bats: stats.at_bats.unwrap_or_default(),
+            doubles: stats.doubles.unwrap_or_default(),
+            triples: stats.triples.unwrap_or_default(),
+            home_runs: stats.home_runs.unwrap_or_default(),
+            rbi: stats.rbi.unwrap_or_default(),
+            stolen_bases: stats.stolen_bases.unwrap_or_default(),
+        }
+    }
+
+    /// Multiple hits, a home run, multiple RBIs or a stolen base
+    fn is_standout(&self) -> bool {
+        self.hits >= 2 || self.home_runs > 0 || self.rbi >= 2 || self.stolen_bases > 0
+    }
+}
+
+impl Display for Performance {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Self { name, hits, at_bats, doubles, triples, home_runs, rbi, stolen_bases } = self;
+        write!(f, "{name} {hits}-{at_bats}")?;
+        for (count, label) in [(doubles, "2B"), (triples, "3B"), (home_runs, "HR"), (rbi, "RBI"), (stolen_bases, "SB")] {
+            match count {
+                0 => {},
+                1 => write!(f, ", {label}")?,
+                count => write!(f, ", {count} {label}")?,
+            }
+        }
+        Ok(())
+    }
+}
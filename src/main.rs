@@ -1,41 +1,80 @@
+use std::cell::RefCell;
+use std::convert::Infallible;
+use std::fmt::Write;
 use std::io::{stderr, stdout};
 use std::ops::ControlFlow;
 use std::thread;
 use std::time::Duration;
 
+use components::bullpen::{Bullpen, BULLPEN_DAYS};
 use components::decisions::Decisions;
 use components::linescore::LineScore;
 use components::next_game::NextGame;
-use components::pitching::PitcherLineupEntry;
+use components::pitching::{PitcherLineupEntry, ProbablePitcher};
 use components::record_against::RecordAgainst;
-use components::standings::Standings;
+use components::series::Series;
+use components::standings::{LeagueStanding, Standings, StandingsSplit};
 use crate::posts::final_card::FinalCard;
 use crate::posts::lineup::Lineup;
-use crate::posts::pitching_line::PitcherFinalLine;
+use crate::posts::pitching_change::{PitchingChange, PitchingChangePosts};
 use crate::posts::scoring_play::ScoringPlay;
 use crate::posts::scoring_play_event::ScoringPlayEvent;
+use crate::posts::series_preview::SeriesPreview;
+use crate::posts::status_change::{GameStatus, StatusWatch};
 use crate::posts::Post;
 use crate::util::ffi::{self};
+use crate::util::polling::{self, Backoff, SeenPlays};
 use crate::util::stat::HittingStat;
-use crate::util::statsapi::{get_last_lineup_underscores, modify_abbreviation, BoldingDisplayKind, Score};
+use crate::util::statsapi::{get_last_lineup_underscores, modify_abbreviation, BaseOutState, BoldingDisplayKind, HalfInning, LeadChanges, Outcome, Score};
 use crate::util::{clear_screen, get_team_color_escape, statsapi};
-use anyhow::{bail, Context, Result};
-use chrono::{Datelike, Local, NaiveDate, TimeZone};
+use anyhow::{Context, Result};
+use chrono::{Datelike, Local, NaiveDate, TimeDelta, TimeZone, Utc};
 use chrono_tz::Tz;
 use chrono_tz::Tz::America__Toronto;
 use fxhash::FxHashSet;
-use mlb_api::game::{GameId, LiveFeedRequest, LiveFeedResponse, PlayEvent, PlayStream, PlayStreamEvent};
+use tokio::task::JoinSet;
+use mlb_api::game::{GameId, LiveFeedData, LiveFeedRequest, LiveFeedResponse, PlayEvent, PlayStream, PlayStreamEvent};
 use mlb_api::meta::{EventType, GameType};
 use mlb_api::request::RequestURLBuilderExt;
 use mlb_api::schedule::{ScheduleGame, ScheduleRequest};
 use mlb_api::sport::SportId;
 use mlb_api::venue::VenuesRequest;
 use mlb_api::{venue_hydrations, HomeAway, TeamSide};
-use mlb_api::person::PersonId;
 use mlb_api::stats::derived::era;
 use crate::components::pitching_masterpiece::PitchingMasterpiece;
+use crate::components::top_performers::TopPerformers;
+use crate::components::game_info::GameInfo;
+use crate::posts::box_score::BoxScore;
+use crate::posts::no_hitter_watch::NoHitterWatch;
+use crate::posts::hitter_watch::HitterWatch;
+use crate::posts::pitcher_watch::PitcherWatch;
+use crate::posts::walkoff::Walkoff;
+use crate::posts::inning_summary::{InningSummaries, InningSummary};
+use crate::posts::scoring_batch::ScoringBatcher;
 
 pub const TIMEZONE: Tz = America__Toronto;
+/// Which pitching changes get their own post (never in spring training)
+pub const PITCHING_CHANGE_POSTS: PitchingChangePosts = PitchingChangePosts::BothTeams;
+/// Whether the lineup post lists recent reliever usage
+pub const SHOW_BULLPEN: bool = true;
+/// Which season splits the lineup post shows under the standings
+pub const LINEUP_STANDINGS_SPLITS: &[StandingsSplit] = &[StandingsSplit::RunDifferential, StandingsSplit::HomeRoad];
+/// Whether the final card ends with attendance, duration and weather
+pub const SHOW_GAME_INFO: bool = true;
+/// The inning a no-hitter or perfect game has to last through before it gets posted about
+pub const NO_HITTER_WATCH_INNING: usize = 6;
+/// Whether a summary is posted at the end of each half inning, and what happens to 1-2-3 innings
+pub const INNING_SUMMARIES: InningSummaries = InningSummaries::CollapseQuiet;
+/// If set, scoring plays are held and sent together at the end of the half inning, or once this long passes without another run
+pub const SCORING_PLAY_BATCHING: Option<Duration> = None;
+/// Whether the final card counts how many times the lead changed hands
+pub const SHOW_LEAD_CHANGES: bool = true;
+/// Whether a full box score is posted after the final card
+pub const SEND_BOX_SCORE: bool = true;
+/// Whether the final card's top performers include the other team's hitters
+pub const TOP_PERFORMERS_BOTH_TEAMS: bool = true;
+/// Which season splits the final card shows under the standings
+pub const FINAL_CARD_STANDINGS_SPLITS: &[StandingsSplit] = &[StandingsSplit::RunDifferential, StandingsSplit::Pythagorean, StandingsSplit::OneRun, StandingsSplit::ExtraInnings];
 
 pub mod util;
 pub mod posts;
@@ -71,38 +110,50 @@ async fn main() {
 }
 
 async fn main0() -> Result<()> {
-    pub async fn await_filled_batting_order(mut live_feed: Result<LiveFeedResponse, GameId>, cheering_for: TeamSide) -> Result<LiveFeedResponse> {
-        let mut dots = 0;
+    pub async fn await_filled_batting_order(live_feed: Result<LiveFeedResponse, GameId>, cheering_for: TeamSide, status_watch: &mut StatusWatch) -> Result<Option<LiveFeedResponse>> {
         ffi::set_cursor_visible(false);
 
         let mut live_feed = match live_feed {
             Ok(game) => game,
-            Err(id) => LiveFeedRequest::builder().id(id).build_and_get().await?,
+            Err(id) => polling::get_live_feed(id).await?,
         };
 
         loop {
+            if let Some(post) = status_watch.update(GameStatus::from_data(&live_feed.data)) {
+                println!();
+                post.send()?;
+            }
+            if status_watch.status().is_called_off() {
+                ffi::set_cursor_visible(true);
+                return Ok(None)
+            }
+
             if live_feed.live.boxscore.teams.as_ref().choose(cheering_for).batting_order.is_empty() {
-                print!("\rLoading{: <pad$}", ".".repeat(dots + 1), pad = 3 - dots);
-                ffi::flush();
-                dots = (dots + 1) % 3;
-                live_feed = LiveFeedRequest::builder().id(live_feed.id).build_and_get().await?;
-                tokio::time::sleep(Duration::new(live_feed.meta.recommended_poll_rate as _, 0)).await;
+                let first_pitch = live_feed.data.datetime.datetime;
+                let interval = polling::pregame_poll_interval(first_pitch - Utc::now().naive_utc(), Duration::new(live_feed.meta.recommended_poll_rate as _, 0));
+                polling::countdown(first_pitch, "First pitch", interval).await;
+                live_feed = polling::get_live_feed(live_feed.id).await?;
             } else {
-                println!("         ");
+                println!("\r{: <64}", "");
                 break;
             }
         }
         ffi::set_cursor_visible(true);
-        Ok(live_feed)
+        Ok(Some(live_feed))
     }
 
     ffi::set_cursor_visible(false);
     let (game_id, cheering_for, stats) = get_id()?;
     ffi::set_cursor(0, 0);
-    let mut live_feed: LiveFeedResponse = LiveFeedRequest::builder().id(game_id).build_and_get()?;
-    let (mut lineup_post, next_game) = lines(&live_feed, cheering_for, stats).await?;
+    let live_feed: LiveFeedResponse = LiveFeedRequest::builder().id(game_id).build_and_get()?;
+    let (mut lineup_post, next_game, series_preview) = lines(&live_feed, cheering_for, stats).await?;
+    if let Some(series_preview) = series_preview {
+        series_preview.send()?;
+    }
     lineup_post.send_with_settings(true, true, true)?;
-    live_feed = await_filled_batting_order(Ok(live_feed), cheering_for).await?;
+    let HomeAway { home: home_abbreviation, away: away_abbreviation } = live_feed.data.teams.as_ref().map(|team| team.name.abbreviation.as_str());
+    let mut status_watch = StatusWatch::new(format!("{away_abbreviation} @ {home_abbreviation}"));
+    let Some(live_feed) = await_filled_batting_order(Ok(live_feed), cheering_for, &mut status_watch).await? else { return Ok(()) };
     ffi::set_cursor(0, 0);
     let lineup = statsapi::lineup(&live_feed.live.boxscore.teams.choose(cheering_for), stats, statsapi::should_show_stats(live_feed.data.game_type), live_feed.data.season)?;
     lineup_post.update_lineup(lineup);
@@ -112,7 +163,9 @@ async fn main0() -> Result<()> {
         cheering_for,
         lineup_post.standings,
         lineup_post.record,
+        lineup_post.series,
         next_game,
+        status_watch,
     ).await?;
     Ok(())
 }
@@ -327,112 +380,329 @@ async fn posts_loop(
     cheering_for: TeamSide,
     mut standings: Standings,
     mut record: RecordAgainst,
+    mut series: Option<Series>,
     next_game: Option<NextGame>,
+    status_watch: StatusWatch,
 ) -> Result<()> {
+    let game_id = live_feed.id;
+    let all_players = live_feed.data.players.clone();
     let our_abbreviation = modify_abbreviation(&live_feed.data.teams.as_ref().choose(cheering_for).name);
     let mut scoring_plays = String::new();
-    let HomeAway { home: home_starter_id, away: away_starter_id } = live_feed.data.probable_pitchers.as_ref().map(|person| person.id);
-
-    PlayStream::with_presupplied_feed::<anyhow::Error, _>(live_feed, async |event, meta, data, linescore, boxscore| {
-        match event {
-            PlayStreamEvent::EndPlay(play) => {
-                if play.about.is_scoring_play == Some(true) {
-                    let scoring_play = ScoringPlay::from_play(
-                        &play,
-                        &data.teams.home.name.abbreviation,
-                        &data.teams.away.name.abbreviation,
-                        &live_feed.data.players,
-                    )?;
-                    writeln!(&mut scoring_plays, "{}", scoring_play.as_one_liner())?;
-                    scoring_play.send()?;
-                }
+    let mut lead_changes = LeadChanges::default();
+    let mut no_hitter_watch = NoHitterWatch::new(NO_HITTER_WATCH_INNING);
+    let mut hitter_watch = HitterWatch::new(&live_feed.live.boxscore).await;
+    let mut pitcher_watch = PitcherWatch::new(&live_feed.live.boxscore).await;
+    let mut half_inning = HalfInning::default();
+    // shared with the timer below, since a quiet period can run out without another event to notice it
+    let scoring_batcher = RefCell::new(ScoringBatcher::new(SCORING_PLAY_BATCHING));
+    let starter_ids = live_feed.data.probable_pitchers.as_ref().map(|person| person.id);
+    let mut seen_plays = SeenPlays::default();
+    let mut backoff = Backoff::new();
+    let status_watch = RefCell::new(status_watch);
+    let mut live_feed = Some(live_feed);
+
+    loop {
+        let feed = match live_feed.take() {
+            Some(feed) => feed,
+            None => polling::get_live_feed(game_id).await?,
+        };
+        if let Some(interruption) = check_status(&mut status_watch.borrow_mut(), &feed.data)? {
+            match interruption {
+                Interruption::Suspended => match await_resumption(game_id, &mut status_watch.borrow_mut()).await? {
+                    Some(feed) => live_feed = Some(feed),
+                    None => return Ok(()),
+                },
+                Interruption::CalledOff => return Ok(()),
             }
-            PlayStreamEvent::PlayEvent(play_event, play) => {
-                match play_event {
-                    PlayEvent::Action { details, common, .. } => {
-                        match details.event {
-                            EventType::PitchingSubstitution if data.game_type != GameType::SpringTraining => {
-                                let new_pitcher = common.player.unwrap_or(PersonId::new(0));
-                                let new_pitcher_team = boxscore.teams.as_ref()
-                                    .map(|team| team.pitchers.iter().nth(2).is_some_and(|second_pitcher| second_pitcher == new_pitcher));
-                                let id = if new_pitcher_team.home {
-                                    home_starter_id
-                                } else {
-                                    away_starter_id
-                                };
-                                let final_line = PitcherFinalLine::from_play(boxscore.find_player_with_game_data(id).context("Pitcher did not play in the game?")?);
-                                final_line.send()?;
-                            },
-                            event @ (EventType::PassedBall | EventType::WildPitch | EventType::StolenBaseHome) if details.is_scoring_play => {
-                                let simplified_event_type = match event {
-                                    EventType::PassedBall | EventType::WildPitch => EventType::WildPitch,
-                                    EventType::StolenBaseHome => EventType::StolenBase,
-                                    _ => bail!("Unknown scoring play event type"),
-                                };
-                                let scoring_play_event = ScoringPlayEvent::from_play(
-                                    (details, common),
-                                    play,
-                                    &data.teams.home.name.abbreviation,
-                                    &data.teams.away.name.abbreviation,
-                                    &live_feed.data.players,
-                                    simplified_event_type,
-                                )?;
-                                writeln!(&mut scoring_plays, "{}", scoring_play_event.as_one_liner())?;
-                                scoring_play_event.send()?
-                            },
+            continue
+        }
+        let poll_rate = Duration::new(feed.meta.recommended_poll_rate as _, 0);
+        // errors from our own handling are not network errors, so they shouldn't be retried
+        let mut fatal = None;
+
+        let stream = PlayStream::with_presupplied_feed::<anyhow::Error, _>(feed, async |event, meta, data, linescore, boxscore| {
+            let result: Result<ControlFlow<()>> = async {
+                if !seen_plays.insert(&event) {
+                    return Ok(ControlFlow::Continue(()))
+                }
+                backoff.reset();
+
+                match event {
+                    PlayStreamEvent::EndPlay(play) => {
+                        if play.about.is_scoring_play == Some(true) {
+                            let scoring_play = ScoringPlay::from_play(
+                                &play,
+                                &data.teams.home.name.abbreviation,
+                                &data.teams.away.name.abbreviation,
+                                lead_changes.score(),
+                                &all_players,
+                            )?;
+                            lead_changes.update(play.result.away_score, play.result.home_score);
+                            writeln!(&mut scoring_plays, "{}", scoring_play.as_one_liner())?;
+                            let batting_abbreviation = &data.teams.as_ref().choose(play.about.inning_half.bats()).name.abbreviation;
+                            if let Some(batch) = scoring_batcher.borrow_mut().add(batting_abbreviation, play.about.inning_half, play.about.inning, scoring_play.clone()) {
+                                batch.send()?;
+                            }
+                            if scoring_play.is_walkoff() {
+                                if let Some(batch) = scoring_batcher.borrow_mut().flush() {
+                                    batch.send()?;
+                                }
+                                Walkoff::new(&play, scoring_play.event(), boxscore, linescore, data.teams.as_ref())?.send()?;
+                            }
+                        }
+                        half_inning.add(&play);
+                        let abbreviations = data.teams.as_ref().map(|team| team.name.abbreviation.as_str());
+                        for callout in hitter_watch.update(&play, boxscore, abbreviations) {
+                            callout.send()?;
+                        }
+                        for callout in pitcher_watch.update(&play, &half_inning, boxscore, abbreviations) {
+                            callout.send()?;
+                        }
+                        if let Some(alert) = no_hitter_watch.update(&play, boxscore.teams.as_ref(), abbreviations) {
+                            alert.send()?;
+                        }
+                        if play.count.outs == 3 {
+                            if let Some(batch) = scoring_batcher.borrow_mut().flush() {
+                                batch.send()?;
+                            }
+                            if let Some(summary) = InningSummary::new(INNING_SUMMARIES, &play, &half_inning, &pitcher_watch, boxscore, linescore, data.teams.as_ref())? {
+                                summary.send()?;
+                            }
+                            half_inning = HalfInning::default();
+                        }
+                    }
+                    PlayStreamEvent::PlayEvent(play_event, play) => {
+                        match play_event {
+                            PlayEvent::Action { details, common, .. } => {
+                                match details.event {
+                                    EventType::PitchingSubstitution if data.game_type != GameType::SpringTraining => {
+                                        let pitching_side = !play.about.inning_half.bats();
+                                        let team = boxscore.teams.as_ref().choose(pitching_side);
+                                        let incoming_id = common.player.context("Expected a pitcher to come in")?;
+                                        let outgoing_id = match team.pitchers.iter().position(|&id| id == incoming_id) {
+                                            Some(idx) => idx.checked_sub(1).map(|idx| team.pitchers[idx]),
+                                            None => team.pitchers.last().copied(),
+                                        }.context("Expected a pitcher to be replaced")?;
+                                        let should_post = match PITCHING_CHANGE_POSTS {
+                                            PitchingChangePosts::StarterOnly => outgoing_id == starter_ids.choose(pitching_side),
+                                            PitchingChangePosts::OurTeam => pitching_side == cheering_for,
+                                            PitchingChangePosts::BothTeams => true,
+                                        };
+                                        // a pitcher missing from the feed only costs this post, not the rest of the game
+                                        if should_post
+                                            && let Some(outgoing) = team.players.get(&outgoing_id)
+                                            && let Some(incoming) = team.players.get(&incoming_id)
+                                            && let Some(incoming_player) = all_players.get(&incoming_id)
+                                        {
+                                            let score = lead_changes.score();
+                                            PitchingChange::new(
+                                                &data.teams.as_ref().choose(pitching_side).name.abbreviation,
+                                                outgoing,
+                                                incoming,
+                                                incoming_player.pitch_hand,
+                                                Score::new(
+                                                    data.teams.away.name.abbreviation.clone(),
+                                                    score.away,
+                                                    data.teams.home.name.abbreviation.clone(),
+                                                    score.home,
+                                                    0,
+                                                    play.about.inning_half.bats(),
+                                                    BoldingDisplayKind::None,
+                                                    BoldingDisplayKind::None,
+                                                ),
+                                                play.about.inning,
+                                                play.about.inning_half,
+                                                BaseOutState::from_play(play, common.count.outs),
+                                            ).send()?;
+                                        }
+                                    },
+                                    event if details.is_scoring_play => {
+                                        let scoring_play_event = ScoringPlayEvent::from_play(
+                                            (details, common),
+                                            play,
+                                            &data.teams.home.name.abbreviation,
+                                            &data.teams.away.name.abbreviation,
+                                            lead_changes.score(),
+                                            &all_players,
+                                            event,
+                                        );
+                                        lead_changes.update(details.away_score, details.home_score);
+                                        writeln!(&mut scoring_plays, "{}", scoring_play_event.as_one_liner())?;
+                                        let batting_abbreviation = &data.teams.as_ref().choose(play.about.inning_half.bats()).name.abbreviation;
+                                        if let Some(batch) = scoring_batcher.borrow_mut().add(batting_abbreviation, play.about.inning_half, play.about.inning, scoring_play_event.clone()) {
+                                            batch.send()?;
+                                        }
+                                        if scoring_play_event.is_walkoff() {
+                                            if let Some(batch) = scoring_batcher.borrow_mut().flush() {
+                                                batch.send()?;
+                                            }
+                                            Walkoff::new(play, event, boxscore, linescore, data.teams.as_ref())?.send()?;
+                                        }
+                                    },
+                                    _ => {},
+                                }
+                            }
                             _ => {},
                         }
                     }
+                    PlayStreamEvent::GameEnd(decisions, _, _, _) => {
+                        if let Some(batch) = scoring_batcher.borrow_mut().flush() {
+                            batch.send()?;
+                        }
+                        let last_inning_runs = linescore.innings.last().map(|inning| inning.inning_record.map(|rhe| rhe.runs)).unwrap_or_default();
+                        let is_walkoff = linescore.rhe_totals.home.runs > linescore.rhe_totals.away.runs && linescore.rhe_totals.home.runs - last_inning_runs.home <= linescore.rhe_totals.away.runs;
+
+                        let runs = linescore.rhe_totals.map(|totals| totals.runs);
+                        let outcome = Outcome::from_runs(runs.choose(cheering_for), runs.choose(!cheering_for));
+                        standings.add(outcome);
+                        record.add(outcome);
+                        if let Some(series) = &mut series { series.add(outcome) }
+                        standings.add_splits(runs.choose(cheering_for), runs.choose(!cheering_for), cheering_for, linescore.innings.len() > linescore.scheduled_innings);
+                        // the rivals only refine the standings, so failing to refresh them shouldn't lose the final card
+                        if let Err(e) = standings.update_rivals().await {
+                            eprintln!("Error while updating the standings' rivals: {e}");
+                        }
+
+                        FinalCard {
+                            score: Score::new(
+                                data.teams.away.name.abbreviation.clone(),
+                                linescore.rhe_totals.away.runs,
+                                data.teams.home.name.abbreviation.clone(),
+                                linescore.rhe_totals.home.runs,
+                                linescore.innings.len() as u8,
+                                TeamSide::Home, // does not matter
+                                BoldingDisplayKind::WinningTeam,
+                                if is_walkoff { BoldingDisplayKind::WinningTeam } else { BoldingDisplayKind::None },
+                            ),
+                            standings: (!data.game_type.is_postseason()).then(|| standings.clone()),
+                            record: record.clone(),
+                            series: series.clone(),
+                            next_game: next_game.clone(),
+                            pitching_masterpiece: PitchingMasterpiece::new(boxscore.teams.as_ref().choose(cheering_for), &our_abbreviation),
+                            top_performers: TopPerformers::new(
+                                boxscore.teams.as_ref(),
+                                data.teams.as_ref().map(|team| team.name.abbreviation.as_str()),
+                                (!TOP_PERFORMERS_BOTH_TEAMS).then_some(cheering_for),
+                            ),
+                            linescore: LineScore::new(linescore, data.teams.as_ref())?,
+                            walkoff: is_walkoff.then_some(outcome),
+                            scoring_plays: scoring_plays.trim_end().to_owned(),
+                            lead_changes: SHOW_LEAD_CHANGES.then(|| lead_changes.count()),
+                            decisions: Decisions::new(decisions, boxscore, data.teams.as_ref().map(|team| team.name.abbreviation.as_str()))?,
+                            game_info: SHOW_GAME_INFO.then(|| GameInfo::new(data)),
+                        }.send()?;
+
+                        if SEND_BOX_SCORE {
+                            BoxScore::new(boxscore, data.teams.as_ref().map(|team| team.name.abbreviation.as_str())).send()?;
+                        }
+
+                        return Ok(ControlFlow::Break(()))
+                    }
                     _ => {},
                 }
-            }
-            PlayStreamEvent::GameEnd(decisions, _, _, _stat_leaders) => {
-                let last_inning_runs = linescore.innings.last().map(|inning| inning.inning_record.map(|rhe| rhe.runs)).unwrap_or_default();
-                let is_walkoff = linescore.rhe_totals.home.runs > linescore.rhe_totals.away.runs && linescore.rhe_totals.home.runs - last_inning_runs.home <= linescore.rhe_totals.away.runs;
-
-                if (linescore.rhe_totals.away.runs > linescore.rhe_totals.home.runs) ^ (cheering_for == TeamSide::Home) {
-                    standings.win();
-                    record.win();
-                } else {
-                    standings.loss();
-                    record.loss();
-                }
 
-                FinalCard {
-                    score: Score::new(
-                        &data.teams.away.name.abbreviation,
-                        &linescore.rhe_totals.away.runs,
-                        &data.teams.home.name.abbreviation,
-                        &linescore.rhe_totals.home.runs,
-                        linescore.innings.len() as u8,
-                        TeamSide::Home, // does not matter
-                        BoldingDisplayKind::WinningTeam,
-                        if is_walkoff { BoldingDisplayKind::WinningTeam } else { BoldingDisplayKind::None },
-                    ),
-                    standings: (!data.game_type.is_postseason()).then_some(standings),
-                    record_text: if data.game_type.is_postseason() { "Series Against" } else { "Record Against" },
-                    record,
-                    next_game,
-                    pitching_masterpiece: PitchingMasterpiece::new(boxscore.teams.as_ref().choose(cheering_for), &our_abbreviation),
-                    linescore: LineScore::new(linescore, data.teams.as_ref())?,
-                    scoring_plays: scoring_plays.trim_end().to_owned(),
-                    decisions: Decisions::new(decisions, boxscore),
-                }.send()?;
-                
-                return Ok(())
-            }
-            _ => {},
+                Ok(ControlFlow::Continue(()))
+            }.await;
+
+            result.or_else(|e| {
+                fatal = Some(e);
+                Ok(ControlFlow::Break(()))
+            })
+        });
+
+        let (interruption, result) = tokio::select! {
+            result = stream => (None, result),
+            interruption = watch_status(game_id, poll_rate, &status_watch) => (Some(interruption?), Ok(())),
+            Err(e) = send_quiet_batches(&scoring_batcher) => return Err(e),
+        };
+
+        if let Some(e) = fatal {
+            return Err(e)
         }
 
-        Ok(ControlFlow::Continue(()))
-    }).await?;
+        match (interruption, result) {
+            (Some(Interruption::CalledOff), _) => return Ok(()),
+            (Some(Interruption::Suspended), _) => match await_resumption(game_id, &mut status_watch.borrow_mut()).await? {
+                Some(feed) => live_feed = Some(feed),
+                None => return Ok(()),
+            },
+            (None, Ok(())) => return Ok(()),
+            // the next feed replays everything missed while disconnected, `seen_plays` skips what was already posted
+            (None, Err(e)) if polling::is_transient(&e) => backoff.wait(&e).await,
+            (None, Err(e)) => return Err(e),
+        }
+    }
+}
+
+/// Posts any status change, and returns whether the game has stopped producing plays
+fn check_status(status_watch: &mut StatusWatch, data: &LiveFeedData) -> Result<Option<Interruption>> {
+    if let Some(post) = status_watch.update(GameStatus::from_data(data)) {
+        post.send()?;
+    }
+    Ok(match status_watch.status() {
+        GameStatus::Suspended { .. } => Some(Interruption::Suspended),
+        status if status.is_called_off() => Some(Interruption::CalledOff),
+        _ => None,
+    })
+}
+
+/// Only returns once the game is interrupted or a feed couldn't be fetched.
+///
+/// Runs on its own timer since the stream only calls back on new events, and a stoppage doesn't produce any.
+async fn watch_status(game_id: GameId, mut poll_rate: Duration, status_watch: &RefCell<StatusWatch>) -> Result<Interruption> {
+    loop {
+        tokio::time::sleep(poll_rate).await;
+        let feed = polling::get_live_feed(game_id).await?;
+        if let Some(interruption) = check_status(&mut status_watch.borrow_mut(), &feed.data)? {
+            return Ok(interruption)
+        }
+        poll_rate = Duration::new(feed.meta.recommended_poll_rate as _, 0);
+    }
+}
+
+/// Only returns if a batch couldn't be sent
+async fn send_quiet_batches(scoring_batcher: &RefCell<ScoringBatcher>) -> Result<Infallible> {
+    loop {
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        let batch = scoring_batcher.borrow_mut().poll();
+        if let Some(batch) = batch {
+            batch.send()?;
+        }
+    }
+}
+
+enum Interruption {
+    Suspended,
+    CalledOff,
+}
+
+/// Returns the feed once the game is back underway, or [`None`] if it was called off instead
+async fn await_resumption(game_id: GameId, status_watch: &mut StatusWatch) -> Result<Option<LiveFeedResponse>> {
+    ffi::set_cursor_visible(false);
+    let feed = loop {
+        let feed = polling::get_live_feed(game_id).await?;
+        if let Some(post) = status_watch.update(GameStatus::from_data(&feed.data)) {
+            println!();
+            post.send()?;
+        }
+        let recommended_poll_rate = Duration::new(feed.meta.recommended_poll_rate as _, 0);
+        match status_watch.status() {
+            GameStatus::Live | GameStatus::Final => break Some(feed),
+            status if status.is_called_off() => break None,
+            GameStatus::Suspended { .. } => tokio::time::sleep(polling::pregame_poll_interval(TimeDelta::MAX, recommended_poll_rate)).await,
+            _ => tokio::time::sleep(recommended_poll_rate).await,
+        }
+    };
+    println!("\r{: <64}", "");
+    ffi::set_cursor_visible(true);
+    Ok(feed)
 }
 
 async fn lines(
     live_feed: &LiveFeedResponse,
     cheering_for: TeamSide,
     hitting_stats: [HittingStat; 2],
-) -> Result<(Lineup, Option<NextGame>)> {
+) -> Result<(Lineup, Option<NextGame>, Option<SeriesPreview>)> {
     venue_hydrations! {
         struct VenueWithTimezone {
             timezone
@@ -452,7 +722,12 @@ async fn lines(
 
     let pitchers = get_pitcher_lines(live_feed, HomeAway::new(&home_abbreviation, &away_abbreviation));
 
-    let (previous_game_id, standings, record, next_game) = response_parsed_values(&live_feed, cheering_for).await?;
+    let (previous_game_id, standings, record, series, series_preview, next_game, recent_games) = response_parsed_values(&live_feed, cheering_for).await?;
+    let bullpen = if SHOW_BULLPEN {
+        Bullpen::new(live_feed.live.boxscore.teams.as_ref().choose(cheering_for), our_id, &live_feed.data.players, datetime.date_naive(), &recent_games).await
+    } else {
+        None
+    };
     let (previous, previous_game_team_with_game_data) = if let Some(game_id) = previous_game_id {
         let live_feed = LiveFeedRequest::builder().id(game_id).build_and_get().await?;
         let cheering_for = if live_feed.data.teams.home.id == our_id { TeamSide::Home } else { TeamSide::Away };
@@ -478,34 +753,40 @@ async fn lines(
         time,
         previous,
         record,
+        series,
         standings,
         pitchers,
         hitting_stats,
         get_last_lineup_underscores(previous_game_team_with_game_data),
-    ), next_game))
+        bullpen,
+    ), next_game, series_preview))
 }
 
-async fn response_parsed_values(live_feed: &LiveFeedResponse, cheering_for: TeamSide) -> Result<(Option<GameId>, Standings, RecordAgainst, Option<NextGame>)> {
+async fn response_parsed_values(live_feed: &LiveFeedResponse, cheering_for: TeamSide) -> Result<(Option<GameId>, Standings, RecordAgainst, Option<Series>, Option<SeriesPreview>, Option<NextGame>, Vec<(NaiveDate, GameId)>)> {
     let our_team = live_feed.data.teams.as_ref().choose(cheering_for);
     let their_team = live_feed.data.teams.as_ref().choose(!cheering_for);
     let game_type = live_feed.data.game_type;
     let start_time = live_feed.data.datetime.datetime;
 
-    let mut all_games = ScheduleRequest::<()>::builder()
+    let all_games = ScheduleRequest::<()>::builder()
         .sport_id(SportId::MLB)
         .date_range(NaiveDate::from_ymd_opt(start_time.year(), 1, 1).context("Valid date")?..=NaiveDate::from_ymd_opt(start_time.year(), 12, 31).context("Valid date")?)
         // .game_type(game_type)
         .team_id(our_team.id)
         .build_and_get().await?
-        .dates.into_iter().flat_map(|date| date.games);
+        .dates.into_iter().flat_map(|date| date.games).collect::<Vec<_>>();
 
     let mut record = RecordAgainst::new(&our_team.name.abbreviation, &their_team.name.abbreviation);
     let mut standings = Standings::new();
     let mut games_played = FxHashSet::<GameId>::with_capacity_and_hasher(162, Default::default());
 
     let mut previous_game_id = None;
+    let date = TIMEZONE.from_utc_datetime(&start_time).date_naive();
+    let mut recent_games = Vec::new();
+    // our results in the current run of games against this opponent
+    let mut matchup_results = Vec::new();
 
-    for game in all_games.by_ref().take_while(|game| game.game_date < start_time && game.status.abstract_game_code.is_finished()) {
+    for game in all_games.iter().take_while(|game| game.game_date < start_time && game.status.abstract_game_code.is_finished()) {
         if !games_played.insert(game.game_id) {
             continue
         }
@@ -513,31 +794,87 @@ async fn response_parsed_values(live_feed: &LiveFeedResponse, cheering_for: Team
         let Some(home_score) = game.teams.home.score else { continue };
         let Some(away_score) = game.teams.away.score else { continue };
 
-        let is_matchup = game.teams.either(|team| team.id == their_team.id);
-        if (home_score.runs_scored > away_score.runs_scored) ^ (our_team.id == game.teams.home.team.id) {
-            if is_matchup { record.loss() }
-            standings.loss();
-        } else {
-            if is_matchup { record.win() }
-            standings.win();
+        // in the postseason only this round's games count
+        let is_matchup = game.teams.either(|team| team.id == their_team.id) && (!game_type.is_postseason() || game.game_type == game_type);
+        let side = if our_team.id == game.teams.home.team.id { TeamSide::Home } else { TeamSide::Away };
+        let runs = HomeAway::new(home_score.runs_scored as usize, away_score.runs_scored as usize);
+        let outcome = Outcome::from_runs(runs.choose(side), runs.choose(!side));
+        if is_matchup { record.add(outcome) }
+        standings.add(outcome);
+        matchup_results.push(outcome);
+        if !game.teams.either(|team| team.id == their_team.id) {
+            matchup_results.clear();
+        }
+        if game.game_type == game_type {
+            // the schedule doesn't say how many innings were played, extra inning games come from the official standings
+            standings.add_splits(runs.choose(side), runs.choose(!side), side, false);
         }
 
         previous_game_id = Some(game.game_id);
+
+        let game_date = TIMEZONE.from_utc_datetime(&game.game_date).date_naive();
+        if (date - game_date).num_days() <= BULLPEN_DAYS as i64 {
+            recent_games.push((game_date, game.game_id));
+        }
+    }
+
+    if game_type == GameType::RegularSeason && let Some(league) = LeagueStanding::new(our_team.id, our_team.league.id, live_feed.data.season, date).await? {
+        standings.set_league(league);
     }
 
-    let next_game = if let Some(game) = all_games.skip_while(|game| game.game_date <= start_time).next() {
+    let series = all_games.iter()
+        .find(|game| game.game_id == live_feed.id)
+        .and_then(|game| Series::new(game, cheering_for, &our_team.name.abbreviation, &their_team.name.abbreviation, &matchup_results));
+
+    let series_preview = if let Some(series) = &series && series.game_number() == 1 {
+        let series_games = all_games.iter()
+            .skip_while(|game| game.game_id != live_feed.id)
+            .take_while(|game| game.teams.either(|team| team.id == their_team.id))
+            .take(series.games_in_series() as usize)
+            .collect::<Vec<_>>();
+        let mut requests = JoinSet::new();
+        for (idx, game) in series_games.iter().enumerate() {
+            let game_id = game.game_id;
+            requests.spawn(async move { (idx, LiveFeedRequest::builder().id(game_id).build_and_get().await) });
+        }
+        let mut live_feeds = std::iter::repeat_with(|| None).take(series_games.len()).collect::<Vec<_>>();
+        while let Some(result) = requests.join_next().await {
+            let (idx, live_feed) = result?;
+            // a game that's too far out to have a feed yet just has TBD starters
+            live_feeds[idx] = live_feed.ok();
+        }
+        let mut games = Vec::with_capacity(series_games.len());
+        for (game, live_feed) in series_games.into_iter().zip(live_feeds) {
+            let abbreviations = if game.teams.home.team.id == our_team.id {
+                HomeAway::new(our_team.name.abbreviation.as_str(), their_team.name.abbreviation.as_str())
+            } else {
+                HomeAway::new(their_team.name.abbreviation.as_str(), our_team.name.abbreviation.as_str())
+            };
+            let pitchers = match &live_feed {
+                Some(live_feed) => get_pitcher_lines(live_feed, abbreviations),
+                None => abbreviations.map(ProbablePitcher::tbd),
+            };
+            games.push((TIMEZONE.from_utc_datetime(&game.game_date), pitchers));
+        }
+        let title = format!("{our} {vs} {their}", our = our_team.name.abbreviation, vs = HomeAway::new("vs.", "@").choose(cheering_for), their = their_team.name.abbreviation);
+        Some(SeriesPreview::new(title, games))
+    } else {
+        None
+    };
+
+    let next_game = if let Some(game) = all_games.iter().find(|game| game.game_date > start_time) {
         NextGame::new(game, our_team.id).await?
     } else {
         None
     };
 
-    Ok((previous_game_id, standings, record, next_game))
+    Ok((previous_game_id, standings, record, series, series_preview, next_game, recent_games))
 }
 
-pub fn get_pitcher_lines(live_feed: &LiveFeedResponse, abbreviation: HomeAway<&str>) -> HomeAway<PitcherLineupEntry> {
-    live_feed.data.probable_pitchers.as_ref().map(|person| person.id).combine(abbreviation, |a, b| (a, b)).combine(live_feed.live.boxscore.teams.as_ref(), |(pitcher, abbreviation), team| {
-        let pitcher = &team.players[&pitcher];
-        let person = &live_feed.data.players[&pitcher];
-        PitcherLineupEntry::new(person.full_name.clone(), person.id, abbreviation.to_owned(), person.pitch_hand, era(pitcher.season_stats.pitching.earned_runs, pitcher.season_stats.pitching.innings_pitched), pitcher.season_stats.pitching.innings_pitched.unwrap_or_default())
+pub fn get_pitcher_lines(live_feed: &LiveFeedResponse, abbreviation: HomeAway<&str>) -> HomeAway<ProbablePitcher> {
+    live_feed.data.probable_pitchers.as_ref().map(|person| person.id).combine(abbreviation, |a, b| (a, b)).combine(live_feed.live.boxscore.teams.as_ref(), |(id, abbreviation), team| {
+        let Some(pitcher) = team.players.get(&id) else { return ProbablePitcher::tbd(abbreviation) };
+        let Some(person) = live_feed.data.players.get(&id) else { return ProbablePitcher::tbd(abbreviation) };
+        ProbablePitcher::Announced(PitcherLineupEntry::new(person.full_name.clone(), person.id, abbreviation.to_owned(), person.pitch_hand, era(pitcher.season_stats.pitching.earned_runs, pitcher.season_stats.pitching.innings_pitched), pitcher.season_stats.pitching.innings_pitched.unwrap_or_default()))
     })
 }
@@ -0,0 +1,204 @@
+use std::fmt::{Display, Formatter};
+use mlb_api::game::{Boxscore, PlayerWithGameData, TeamWithGameData};
+use mlb_api::stats::{CountingStat, InningsPitched};
+use mlb_api::stats::derived::avg;
+use mlb_api::HomeAway;
+use crate::posts::Post;
+
+const NAME_WIDTH: usize = 18;
+
+#[derive(Clone)]
+pub struct BoxScore {
+    teams: HomeAway<TeamBoxScore>,
+}
+
+impl BoxScore {
+    pub fn new(boxscore: &Boxscore, abbreviations: HomeAway<&str>) -> Self {
+        Self {
+            teams: boxscore.teams.as_ref().combine(abbreviations, TeamBoxScore::new),
+        }
+    }
+}
+
+impl Display for BoxScore {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let HomeAway { home, away } = &self.teams;
+        writeln!(f, "## Box Score")?;
+        for team in [away, home] {
+            writeln!(f, "### __{abbreviation} Batting__", abbreviation = team.abbreviation)?;
+            writeln!(f, "```")?;
+            writeln!(f, "{:<NAME_WIDTH$} {:>2} {:>2} {:>2} {:>3} {:>2} {:>2} {:>5}", "", "AB", "R", "H", "RBI", "BB", "K", "AVG")?;
+            for batter in &team.batters {
+                writeln!(f, "{batter}")?;
+            }
+            writeln!(f, "```")?;
+            write!(f, "{notes}", notes = team.notes)?;
+        }
+        for team in [away, home] {
+            writeln!(f, "### __{abbreviation} Pitching__", abbreviation = team.abbreviation)?;
+            writeln!(f, "```")?;
+            writeln!(f, "{:<NAME_WIDTH$} {:>4} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>6}", "", "IP", "H", "R", "ER", "BB", "K", "HR", "P-S")?;
+            for pitcher in &team.pitchers {
+                writeln!(f, "{pitcher}")?;
+            }
+            writeln!(f, "```")?;
+        }
+        Ok(())
+    }
+}
+
+impl Post for BoxScore {}
+
+#[derive(Clone)]
+struct TeamBoxScore {
+    abbreviation: String,
+    batters: Vec<BattingLine>,
+    pitchers: Vec<BoxPitchingLine>,
+    notes: Notes,
+}
+
+impl TeamBoxScore {
+    fn new(team: &TeamWithGameData, abbreviation: &str) -> Self {
+        let players = |ids: &[_]| ids.iter().filter_map(|id| team.players.get(id)).collect::<Vec<_>>();
+        let batters = players(&team.batters);
+        let pitchers = players(&team.pitchers);
+        // pitchers can make errors without ever batting
+        let fielders = batters.iter().chain(pitchers.iter().filter(|pitcher| !team.batters.contains(&pitcher.person.id))).copied().collect::<Vec<_>>();
+        Self {
+            abbreviation: abbreviation.to_owned(),
+            batters: batters.iter().map(|batter| BattingLine::new(batter)).collect(),
+            pitchers: pitchers.iter().map(|pitcher| BoxPitchingLine::new(pitcher)).collect(),
+            notes: Notes::new(team, &batters, &fielders),
+        }
+    }
+}
+
+#[derive(Clone)]
+struct BattingLine {
+    name: String,
+    at_bats: CountingStat,
+    runs: CountingStat,
+    hits: CountingStat,
+    rbi: CountingStat,
+    walks: CountingStat,
+    strikeouts: CountingStat,
+    avg: String,
+}
+
+impl BattingLine {
+    fn new(batter: &PlayerWithGameData) -> Self {
+        let stats = &batter.stats.hitting;
+        let season = &batter.season_stats.hitting;
+        Self {
+            name: batter.boxscore_name.clone(),
+            at_bats: stats.at_bats.unwrap_or_default(),
+            runs: stats.runs.unwrap_or_default(),
+            hits: stats.hits.unwrap_or_default(),
+            rbi: stats.rbi.unwrap_or_default(),
+            walks: stats.base_on_balls.unwrap_or_default(),
+            strikeouts: stats.strikeouts.unwrap_or_default(),
+            avg: if season.at_bats.unwrap_or_default() > 0 { avg(season.hits, season.at_bats).to_string() } else { "-".to_owned() },
+        }
+    }
+}
+
+impl Display for BattingLine {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Self { name, at_bats, runs, hits, rbi, walks, strikeouts, avg } = self;
+        write!(f, "{name:<NAME_WIDTH$.NAME_WIDTH$} {at_bats:>2} {runs:>2} {hits:>2} {rbi:>3} {walks:>2} {strikeouts:>2} {avg:>5}")
+    }
+}
+
+#[derive(Clone)]
+struct BoxPitchingLine {
+    name: String,
+    innings_pitched: InningsPitched,
+    hits: CountingStat,
+    runs: CountingStat,
+    earned_runs: CountingStat,
+    walks: CountingStat,
+    strikeouts: CountingStat,
+    home_runs: CountingStat,
+    pitches: CountingStat,
+    strikes: CountingStat,
+}
+
+impl BoxPitchingLine {
+    fn new(pitcher: &PlayerWithGameData) -> Self {
+        let stats = &pitcher.stats.pitching;
+        Self {
+            name: pitcher.boxscore_name.clone(),
+            innings_pitched: stats.innings_pitched.unwrap_or_default(),
+            hits: stats.hits.unwrap_or_default(),
+            runs: stats.runs.unwrap_or_default(),
+            earned_runs: stats.earned_runs.unwrap_or_default(),
+            walks: stats.base_on_balls.unwrap_or_default(),
+            strikeouts: stats.strikeouts.unwrap_or_default(),
+            home_runs: stats.home_runs.unwrap_or_default(),
+            pitches: stats.number_of_pitches.unwrap_or_default(),
+            strikes: stats.strikes.unwrap_or_default(),
+        }
+    }
+}
+
+impl Display for BoxPitchingLine {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Self { name, innings_pitched, hits, runs, earned_runs, walks, strikeouts, home_runs, pitches, strikes } = self;
+        // padding is only applied to strings
+        let innings_pitched = innings_pitched.to_string();
+        let pitches_strikes = format!("{pitches}-{strikes}");
+        write!(f, "{name:<NAME_WIDTH$.NAME_WIDTH$} {innings_pitched:>4} {hits:>2} {runs:>2} {earned_runs:>2} {walks:>2} {strikeouts:>2} {home_runs:>2} {pitches_strikes:>6}")
+    }
+}
+
+#[derive(Clone)]
+struct Notes {
+    doubles: String,
+    home_runs: String,
+    stolen_bases: String,
+    caught_stealing: String,
+    errors: String,
+    left_on_base: CountingStat,
+    risp: Option<String>,
+}
+
+impl Notes {
+    fn new(team: &TeamWithGameData, batters: &[&PlayerWithGameData], fielders: &[&PlayerWithGameData]) -> Self {
+        let list = |players: &[&PlayerWithGameData], stat: fn(&PlayerWithGameData) -> Option<CountingStat>| {
+            players.iter()
+                .filter_map(|player| Some((&player.boxscore_name, stat(player).filter(|&count| count > 0)?)))
+                .map(|(name, count)| if count > 1 { format!("{name} ({count})") } else { name.clone() })
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        Self {
+            doubles: list(batters, |player| player.stats.hitting.doubles.ok()),
+            home_runs: list(batters, |player| player.stats.hitting.home_runs.ok()),
+            stolen_bases: list(batters, |player| player.stats.hitting.stolen_bases.ok()),
+            caught_stealing: list(batters, |player| player.stats.hitting.caught_stealing.ok()),
+            errors: list(fielders, |player| player.stats.fielding.errors.ok()),
+            left_on_base: team.team_stats.hitting.left_on_base.unwrap_or_default(),
+            // only available pre-formatted, ex: "2-for-9."
+            risp: team.sectioned_labelled_values.iter()
+                .flat_map(|section| &section.values)
+                .find(|field| field.label == "Team RISP")
+                .map(|field| field.value.trim_end_matches('.').to_owned()),
+        }
+    }
+}
+
+impl Display for Notes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Self { doubles, home_runs, stolen_bases, caught_stealing, errors, left_on_base, risp } = self;
+        for (label, list) in [("2B", doubles), ("HR", home_runs), ("SB", stolen_bases), ("CS", caught_stealing), ("E", errors)] {
+            if !list.is_empty() {
+                writeln!(f, "**{label}**: {list}")?;
+            }
+        }
+        writeln!(f, "**LOB**: {left_on_base}")?;
+        if let Some(risp) = risp {
+            writeln!(f, "**RISP**: {risp}")?;
+        }
+        Ok(())
+    }
+}
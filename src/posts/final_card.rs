@@ -1,35 +1,60 @@
 use crate::components::decisions::Decisions;
+use crate::components::game_info::GameInfo;
 use crate::components::linescore::LineScore;
 use crate::components::next_game::NextGame;
 use crate::components::record_against::RecordAgainst;
+use crate::components::series::Series;
 use crate::components::standings::Standings;
-use crate::util::statsapi::Score;
+use crate::util::statsapi::{Outcome, Score};
 use std::fmt::{Display, Formatter};
 use crate::components::pitching_masterpiece::PitchingMasterpiece;
+use crate::components::top_performers::TopPerformers;
 use crate::posts::Post;
 
 #[derive(Clone)]
 pub struct FinalCard {
     pub score: Score,
     pub standings: Option<Standings>,
-    pub record_text: &'static str,
     pub record: RecordAgainst,
+    pub series: Option<Series>,
     pub next_game: Option<NextGame>,
     pub pitching_masterpiece: Option<PitchingMasterpiece>,
+    pub top_performers: Option<TopPerformers>,
     pub linescore: LineScore,
+    /// Our result, if the game ended on a walk-off
+    pub walkoff: Option<Outcome>,
     pub scoring_plays: String,
+    pub lead_changes: Option<usize>,
     pub decisions: Option<Decisions>,
+    pub game_info: Option<GameInfo>,
 }
 
 impl Display for FinalCard {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let Self { score, standings, record_text, record, next_game, pitching_masterpiece: pitching_masterpiece, linescore: line_score, scoring_plays, decisions } = self;
-        writeln!(f, "## Final Score")?;
+        let Self { score, standings, record, series, next_game, pitching_masterpiece: pitching_masterpiece, top_performers, linescore: line_score, walkoff, scoring_plays, lead_changes, decisions, game_info } = self;
+        match walkoff {
+            Some(Outcome::Win) => writeln!(f, "## Final Score *|* Walk-off Win")?,
+            Some(Outcome::Loss) => writeln!(f, "## Final Score *|* Walk-off Loss")?,
+            Some(Outcome::Tie) | None => writeln!(f, "## Final Score")?,
+        }
         writeln!(f, "{score:?}")?;
         if let Some(standings) = standings {
             writeln!(f, "Standings: {standings}")?;
+            let splits = standings.splits(crate::FINAL_CARD_STANDINGS_SPLITS);
+            if !splits.is_empty() {
+                writeln!(f, "{splits}")?;
+            }
+            if let Some(magic_numbers) = standings.magic_numbers().filter(|magic_numbers| !magic_numbers.is_empty()) {
+                writeln!(f, "{magic_numbers}")?;
+            }
+        }
+        // in the postseason the series is the only record that matters
+        if !series.as_ref().is_some_and(Series::is_postseason) {
+            writeln!(f, "Record Against: {record}")?;
+        }
+        if let Some(series) = series {
+            writeln!(f, "{series}")?;
         }
-        writeln!(f, "{record_text}: {record}")?;
         if let Some(next_game) = next_game {
             writeln!(f, "Next Game: {next_game}")?;
         }
@@ -40,10 +65,20 @@ impl Display for FinalCard {
         writeln!(f, "{line_score}")?;
         writeln!(f, "### __Scoring Plays__")?;
         writeln!(f, "{scoring_plays}")?;
+        if let Some(lead_changes) = lead_changes {
+            writeln!(f, "Lead Changes: {lead_changes}")?;
+        }
+        if let Some(top_performers) = top_performers {
+            writeln!(f, "### __Top Performers__")?;
+            writeln!(f, "{top_performers}")?;
+        }
         if let Some(decisions) = decisions {
             writeln!(f, "### __Pitcher Decisions__")?;
             writeln!(f, "{decisions}")?;
         }
+        if let Some(game_info) = game_info {
+            writeln!(f, "-# {game_info}")?;
+        }
         write!(f, "> ")?;
 
         Ok(())
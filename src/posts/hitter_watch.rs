@@ -0,0 +1,161 @@
+use crate::posts::Post;
+use crate::util::nth;
+use crate::util::statsapi::GameLine;
+use fxhash::{FxHashMap, FxHashSet};
+use mlb_api::game::{Boxscore, Inning, InningHalf, Play};
+use mlb_api::person::PersonId;
+use mlb_api::single_stat;
+use mlb_api::stats::CountingStat;
+use mlb_api::HomeAway;
+use std::fmt::{Display, Formatter};
+use tokio::task::JoinSet;
+
+/// Career home runs are called out every this many
+const HOME_RUN_MILESTONE: CountingStat = 100;
+/// Career hits are called out every this many
+const HIT_MILESTONE: CountingStat = 500;
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+enum Callout {
+    CycleWatch { missing: &'static str },
+    Cycle,
+    MultiHomer(CountingStat),
+    FiveHits,
+    Milestone { stat: &'static str, total: CountingStat },
+}
+
+impl Display for Callout {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::CycleWatch { missing } => write!(f, "Cycle Watch (needs a {missing})"),
+            Self::Cycle => write!(f, "Hits for the Cycle"),
+            Self::MultiHomer(2) => write!(f, "Second Home Run"),
+            Self::MultiHomer(3) => write!(f, "Third Home Run"),
+            Self::MultiHomer(n) => write!(f, "{n} Home Runs"),
+            Self::FiveHits => write!(f, "Five-Hit Game"),
+            Self::Milestone { stat, total } => write!(f, "Career {stat} #{total}"),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct HitterCallout {
+    team_abbreviation: String,
+    name: String,
+    callout: Callout,
+    hits: CountingStat,
+    at_bats: CountingStat,
+    half: InningHalf,
+    inning: Inning,
+    description: String,
+}
+
+impl Display for HitterCallout {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Self { team_abbreviation, name, callout, hits, at_bats, half, inning, description } = self;
+        writeln!(f, "## :star: {team_abbreviation} {name}: {callout} :star:")?;
+        writeln!(f, "{half} **{inning}**: {description}", half = half.three_char(), inning = nth(**inning))?;
+        writeln!(f, "{name} is {hits}-{at_bats} today")?;
+        Ok(())
+    }
+}
+
+impl Post for HitterCallout {}
+
+#[derive(Copy, Clone, Default)]
+struct CareerTotals {
+    home_runs: CountingStat,
+    hits: CountingStat,
+}
+
+pub struct HitterWatch {
+    /// Before this game, only for the starting lineups
+    careers: FxHashMap<PersonId, CareerTotals>,
+    today: FxHashMap<PersonId, GameLine>,
+    posted: FxHashSet<(PersonId, Callout)>,
+}
+
+impl HitterWatch {
+    /// Fetches career totals for both starting lineups at once; a player whose stats can't be fetched just won't get milestone callouts.
+    pub async fn new(boxscore: &Boxscore) -> Self {
+        let mut requests = JoinSet::new();
+        for team in [&boxscore.teams.away, &boxscore.teams.home] {
+            for &id in &team.batting_order {
+                requests.spawn(async move { (id, single_stat!(Career + Hitting for id).await) });
+            }
+        }
+        let mut careers = FxHashMap::default();
+        while let Some(result) = requests.join_next().await {
+            let Ok((id, Ok(stats))) = result else { continue };
+            careers.insert(id, CareerTotals {
+                home_runs: stats.home_runs.unwrap_or_default(),
+                hits: stats.hits.unwrap_or_default(),
+            });
+        }
+        Self {
+            careers,
+            today: FxHashMap::default(),
+            posted: FxHashSet::default(),
+        }
+    }
+
+    /// Checks the batter after each play; each callout is only posted once per player.
+    pub fn update(&mut self, play: &Play, boxscore: &Boxscore, abbreviations: HomeAway<&str>) -> Vec<HitterCallout> {
+        let batting_side = play.about.inning_half.bats();
+        let team = boxscore.teams.as_ref().choose(batting_side);
+        let id = play.matchup.batter.id;
+        let Some(details) = &play.result.completed_play_details else { return Vec::new() };
+        let line = self.today.entry(id).or_default();
+        *line += GameLine::from_play(play);
+        let line = *line;
+        let Some(batter) = team.players.get(&id) else { return Vec::new() };
+
+        self.callouts(id, line)
+            .into_iter()
+            .filter(|&callout| self.posted.insert((id, callout)))
+            .map(|callout| HitterCallout {
+                team_abbreviation: abbreviations.choose(batting_side).to_owned(),
+                name: batter.boxscore_name.clone(),
+                callout,
+                hits: line.hits,
+                at_bats: line.at_bats,
+                half: play.about.inning_half,
+                inning: play.about.inning,
+                description: details.description.clone(),
+            })
+            .collect()
+    }
+
+    fn callouts(&self, id: PersonId, line: GameLine) -> Vec<Callout> {
+        let GameLine { hits, doubles, triples, home_runs, .. } = line;
+        let singles = hits.saturating_sub(doubles + triples + home_runs);
+
+        let mut callouts = Vec::new();
+        let missing = [(singles, "single"), (doubles, "double"), (triples, "triple"), (home_runs, "home run")]
+            .into_iter()
+            .filter(|&(count, _)| count == 0)
+            .map(|(_, name)| name)
+            .collect::<Vec<_>>();
+        match missing.as_slice() {
+            [] => callouts.push(Callout::Cycle),
+            [missing] => callouts.push(Callout::CycleWatch { missing }),
+            _ => {},
+        }
+        if home_runs >= 2 {
+            callouts.push(Callout::MultiHomer(home_runs));
+        }
+        if hits >= 5 {
+            callouts.push(Callout::FiveHits);
+        }
+        if let Some(career) = self.careers.get(&id) {
+            for (stat, before, today, every) in [("Home Run", career.home_runs, home_runs, HOME_RUN_MILESTONE), ("Hit", career.hits, hits, HIT_MILESTONE)] {
+                let total = before + today;
+                // only the milestone reached today, not one from a previous game
+                if today > 0 && total / every > before / every {
+                    callouts.push(Callout::Milestone { stat, total: total / every * every });
+                }
+            }
+        }
+        callouts
+    }
+}
@@ -0,0 +1,109 @@
+use crate::components::linescore::LineScore;
+use crate::posts::Post;
+use crate::util::nth;
+use crate::posts::pitcher_watch::PitcherWatch;
+use crate::util::statsapi::{BoldingDisplayKind, HalfInning, Score};
+use anyhow::{Context, Result};
+use mlb_api::game::{Boxscore, Inning, InningHalf, Linescore, Play};
+use mlb_api::stats::CountingStat;
+use mlb_api::team::Team;
+use mlb_api::HomeAway;
+use std::fmt::{Display, Formatter};
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum InningSummaries {
+    Off,
+    /// Every half inning gets the full summary
+    All,
+    /// 1-2-3 innings get a single line
+    CollapseQuiet,
+    /// 1-2-3 innings aren't posted
+    SkipQuiet,
+}
+
+#[derive(Clone)]
+pub struct InningSummary {
+    half: InningHalf,
+    inning: Inning,
+    runs: usize,
+    hits: usize,
+    errors: usize,
+    left_on_base: usize,
+    pitcher_name: String,
+    pitches: CountingStat,
+    is_quiet: bool,
+    collapse_quiet: bool,
+    linescore: LineScore,
+    score: Score,
+}
+
+impl InningSummary {
+    /// `play` is the last play of `half_inning`, already added to `pitcher_watch`. `None` if the summary shouldn't be posted.
+    pub fn new(
+        setting: InningSummaries,
+        play: &Play,
+        half_inning: &HalfInning,
+        pitcher_watch: &PitcherWatch,
+        boxscore: &Boxscore,
+        linescore: &Linescore,
+        teams: HomeAway<&Team<()>>,
+    ) -> Result<Option<Self>> {
+        let batting_side = play.about.inning_half.bats();
+        // the linescore may already have moved on to the next inning
+        let inning = linescore.innings.iter().find(|inning| inning.inning == play.about.inning).context("Expected the inning in the linescore")?;
+        let batting = inning.inning_record.as_ref().choose(batting_side);
+        let fielding = inning.inning_record.as_ref().choose(!batting_side);
+        let is_quiet = half_inning.plate_appearances() == 3 && half_inning.free_passes() == 0 && batting.runs == 0 && batting.hits == 0 && fielding.errors == 0;
+
+        match setting {
+            InningSummaries::Off => return Ok(None),
+            InningSummaries::SkipQuiet if is_quiet => return Ok(None),
+            _ => {},
+        }
+
+        let pitcher = boxscore.find_player_with_game_data(play.matchup.pitcher.id).context("Expected the pitcher to play in the game")?;
+
+        Ok(Some(Self {
+            half: play.about.inning_half,
+            inning: play.about.inning,
+            runs: batting.runs,
+            hits: batting.hits,
+            errors: fielding.errors,
+            left_on_base: batting.left_on_base,
+            pitcher_name: pitcher.boxscore_name.clone(),
+            pitches: pitcher_watch.pitches(play.matchup.pitcher.id),
+            is_quiet,
+            collapse_quiet: setting == InningSummaries::CollapseQuiet,
+            linescore: LineScore::through(linescore, teams, play.about.inning, play.about.inning_half)?,
+            score: Score::new(
+                teams.away.name.abbreviation.clone(),
+                play.result.away_score,
+                teams.home.name.abbreviation.clone(),
+                play.result.home_score,
+                0,
+                batting_side,
+                BoldingDisplayKind::None,
+                BoldingDisplayKind::None,
+            ),
+        }))
+    }
+}
+
+impl Display for InningSummary {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Self { half, inning, runs, hits, errors, left_on_base, pitcher_name, pitches, is_quiet, collapse_quiet, linescore, score } = self;
+        let half = half.three_char();
+        let inning = nth(**inning);
+        if *is_quiet && *collapse_quiet {
+            return write!(f, "{score} | End {half} **{inning}**: 1-2-3 ({pitcher_name}, {pitches} pitches)", score = score.code_block())
+        }
+        writeln!(f, "### End of {half} {inning}")?;
+        writeln!(f, "**R**: {runs} *|* **H**: {hits} *|* **E**: {errors} *|* **LOB**: {left_on_base}")?;
+        writeln!(f, "{pitcher_name}: {pitches} pitches")?;
+        writeln!(f, "{linescore}")?;
+        writeln!(f, "{score}", score = score.code_block())?;
+        Ok(())
+    }
+}
+
+impl Post for InningSummary {}
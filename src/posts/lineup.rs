@@ -1,6 +1,8 @@
+use crate::components::bullpen::{Bullpen, BULLPEN_DAYS};
 use crate::components::hitting::HitterLineupEntry;
-use crate::components::pitching::PitcherLineupEntry;
+use crate::components::pitching::ProbablePitcher;
 use crate::components::record_against::RecordAgainst;
+use crate::components::series::Series;
 use crate::components::standings::Standings;
 use crate::util::stat::HittingStat;
 use crate::util::statsapi::Score;
@@ -18,10 +20,12 @@ pub struct Lineup {
     time: String,
     previous: Option<Score>,
     pub record: RecordAgainst,
+    pub series: Option<Series>,
     pub standings: Standings,
-    pitchers: HomeAway<PitcherLineupEntry>,
+    pitchers: HomeAway<ProbablePitcher>,
     hitting_stats: [HittingStat; 2],
     lineup: [HitterLineupEntry; 9],
+    bullpen: Option<Bullpen>,
 }
 
 impl Lineup {
@@ -31,10 +35,12 @@ impl Lineup {
         time: String,
         previous: Option<Score>,
         record: RecordAgainst,
+        series: Option<Series>,
         standings: Standings,
-        pitchers: HomeAway<PitcherLineupEntry>,
+        pitchers: HomeAway<ProbablePitcher>,
         hitting_stats: [HittingStat; 2],
         lineup: [HitterLineupEntry; 9],
+        bullpen: Option<Bullpen>,
     ) -> Self {
         Self {
             datetime,
@@ -42,10 +48,12 @@ impl Lineup {
             time,
             previous,
             record,
+            series,
             standings,
             pitchers,
             hitting_stats,
             lineup,
+            bullpen,
         }
     }
 
@@ -53,29 +61,46 @@ impl Lineup {
         self.lineup = lineup;
     }
     
-    pub fn pitcher_ids(&self) -> (PersonId, PersonId) {
-        (self.home_pitcher_stats.id(), self.away_pitcher_stats.id())
+    pub fn pitcher_ids(&self) -> (Option<PersonId>, Option<PersonId>) {
+        (self.pitchers.home.id(), self.pitchers.away.id())
     }
 }
 
 impl Display for Lineup {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let Self { datetime, title, time, previous, record, standings, home_pitcher_stats, away_pitcher_stats, hitting_stats: [first_stat, second_stat], lineup } = self;
+        let Self { datetime, title, time, previous, record, series, standings, pitchers, hitting_stats: [first_stat, second_stat], lineup, bullpen } = self;
 
         writeln!(f, "# {} {title}", datetime.format("%m*|*%d*|*%y"))?;
         writeln!(f, "First Pitch: {time}")?;
         if let Some(previous) = previous {
             writeln!(f, "Previous Game: {previous:?}")?;
         }
-        writeln!(f, "Record Against: {record}")?;
+        // in the postseason the series is the only record that matters
+        if !series.as_ref().is_some_and(Series::is_postseason) {
+            writeln!(f, "Record Against: {record}")?;
+        }
+        if let Some(series) = series {
+            writeln!(f, "{series}")?;
+        }
         writeln!(f, "Standings: {standings}")?;
+        let splits = standings.splits(crate::LINEUP_STANDINGS_SPLITS);
+        if !splits.is_empty() {
+            writeln!(f, "{splits}")?;
+        }
+        if let Some(magic_numbers) = standings.magic_numbers().filter(|magic_numbers| !magic_numbers.is_empty()) {
+            writeln!(f, "{magic_numbers}")?;
+        }
         writeln!(f, "### __Starting Pitchers__")?;
-        writeln!(f, "{away_pitcher_stats}")?;
-        writeln!(f, "{home_pitcher_stats}")?;
+        writeln!(f, "{away_pitcher}", away_pitcher = pitchers.away)?;
+        writeln!(f, "{home_pitcher}", home_pitcher = pitchers.home)?;
         writeln!(f, "### __Starting Lineup (.{first_stat_value} *|* .{second_stat_value})__", first_stat_value = first_stat.to_string(), second_stat_value = second_stat.to_string())?;
         for line in lineup {
             writeln!(f, "{line}")?;
         }
+        if let Some(bullpen) = bullpen {
+            writeln!(f, "### __Bullpen (Pitches, Last {BULLPEN_DAYS} Days)__")?;
+            writeln!(f, "{bullpen}")?;
+        }
         write!(f, "> ")?;
 
         Ok(())
@@ -1,4 +1,3 @@
-use std::cell::LazyCell;
 use crate::util::ffi::{GetConsoleWindow, SetForegroundWindow};
 use anyhow::{anyhow, Result};
 use std::fmt::Display;
@@ -8,22 +7,45 @@ pub mod scoring_play;
 pub mod scoring_play_event;
 pub mod lineup;
 pub mod final_card;
+pub mod status_change;
+pub mod pitching_change;
+pub mod series_preview;
+pub mod box_score;
+pub mod no_hitter_watch;
+pub mod hitter_watch;
+pub mod pitcher_watch;
+pub mod walkoff;
+pub mod inning_summary;
+pub mod scoring_batch;
+
+/// Discord's message length limit
+pub const MESSAGE_LIMIT: usize = 2000;
 
 pub trait Post: Display {
     fn send(&self) -> Result<()> {
         self.send_with_settings(true, true, false)
     }
 
+    /// Posts longer than [`MESSAGE_LIMIT`] are sent in parts, waiting for enter before copying each part after the first.
     fn send_with_settings(&self, stdout: bool, copy: bool, set_foreground_window: bool) -> Result<()> {
-        let text = LazyCell::new(move || self.to_string());
+        let text = self.to_string();
+        let parts = split_message(&text, MESSAGE_LIMIT);
 
-        if stdout {
-            println!("{}\n\n\n", text.to_string());
-            let _ = std::io::Write::flush(&mut std::io::stdout())?;
-        }
+        for (idx, part) in parts.iter().enumerate() {
+            if idx > 0 && copy {
+                println!("Press enter to copy part {part_number}/{parts_len}", part_number = idx + 1, parts_len = parts.len());
+                // posts are sent from inside the runtime, so the worker thread is handed off while waiting
+                tokio::task::block_in_place(|| std::io::stdin().read_line(&mut String::new()))?;
+            }
+
+            if stdout {
+                println!("{part}\n\n\n");
+                let _ = std::io::Write::flush(&mut std::io::stdout())?;
+            }
 
-        if copy {
-            cli_clipboard::set_contents(text.to_string()).map_err(|_| anyhow!("Failed to set clipboard"))?;
+            if copy {
+                cli_clipboard::set_contents(part.clone()).map_err(|_| anyhow!("Failed to set clipboard"))?;
+            }
         }
 
         if set_foreground_window {
@@ -34,3 +56,90 @@ pub trait Post: Display {
     }
 }
 
+/// Splits on line boundaries, closing and reopening code blocks (with their language tag) that span parts.
+/// A single line longer than `limit` still gets its own (oversized) part.
+pub fn split_message(text: &str, limit: usize) -> Vec<String> {
+    const FENCE: &str = "```";
+
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    // the opening fence of the code block `current` ends inside of, and where it starts in `current`
+    let mut open_fence: Option<(&str, usize)> = None;
+
+    for line in text.lines() {
+        let is_fence = line.trim_start().starts_with(FENCE);
+        // `current` already ends with the newline before `line`, a part ending inside a code block also needs its closing fence.
+        // room for that was reserved when every earlier line was added, so the closing fence itself never starts a new part
+        let reserved = if open_fence.is_some() { 1 + FENCE.len() } else { 0 };
+        let closes_block = is_fence && open_fence.is_some();
+        if !closes_block && !current.is_empty() && current.len() + line.len() + reserved > limit {
+            match open_fence {
+                // nothing in the block yet, so the opening fence moves to the next part instead of leaving an empty block behind
+                Some((fence, start)) if current.len() == start + fence.len() + 1 => {
+                    current.truncate(start);
+                    current.pop();
+                },
+                Some(_) => current.push_str(FENCE),
+                None => {
+                    current.pop();
+                },
+            }
+            let part = core::mem::take(&mut current);
+            if !part.is_empty() {
+                parts.push(part);
+            }
+            if let Some((fence, start)) = &mut open_fence {
+                *start = 0;
+                current.push_str(fence);
+                current.push('\n');
+            }
+        }
+        let start = current.len();
+        current.push_str(line);
+        current.push('\n');
+        if is_fence {
+            open_fence = if open_fence.is_some() { None } else { Some((line, start)) };
+        }
+    }
+
+    let last = current.trim_end();
+    if !last.is_empty() || parts.is_empty() {
+        parts.push(last.to_owned());
+    }
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::split_message;
+
+    #[test]
+    fn reopens_code_blocks_across_parts() {
+        assert_eq!(split_message("Title\n```\naaaa\nbbbb\n```", 20), ["Title\n```\naaaa\n```", "```\nbbbb\n```"]);
+    }
+
+    #[test]
+    fn reopened_code_blocks_keep_their_language() {
+        assert_eq!(split_message("```rust\naaaa\nbbbb\n```", 20), ["```rust\naaaa\n```", "```rust\nbbbb\n```"]);
+    }
+
+    #[test]
+    fn no_empty_code_blocks() {
+        // the closing fence fits in the reserved space
+        assert_eq!(split_message("```\naaaa\n```", 12), ["```\naaaa\n```"]);
+        // the opening fence moves to the part with its contents
+        assert_eq!(split_message("Title\n```rust\naaaa\n```", 20), ["Title", "```rust\naaaa\n```"]);
+    }
+
+    #[test]
+    fn oversized_line_gets_its_own_part() {
+        let long = "x".repeat(30);
+        assert_eq!(split_message(&format!("short\n{long}\nend"), 10), ["short", long.as_str(), "end"]);
+    }
+
+    #[test]
+    fn exact_limit_is_one_part() {
+        assert_eq!(split_message("abcd\nefgh", 9), ["abcd\nefgh"]);
+        assert_eq!(split_message("abcd\nefgh", 8), ["abcd", "efgh"]);
+    }
+}
@@ -0,0 +1,152 @@
+use crate::posts::Post;
+use crate::util::nth;
+use crate::util::statsapi::{BoldingDisplayKind, GameLine, Score};
+use mlb_api::game::{Inning, InningHalf, Play, TeamWithGameData};
+use mlb_api::person::PersonId;
+use mlb_api::stats::CountingStat;
+use mlb_api::HomeAway;
+use std::fmt::{Display, Formatter};
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum MasterpieceKind {
+    PerfectGame,
+    NoHitter,
+}
+
+impl MasterpieceKind {
+    fn of(allowed: &Allowed) -> Option<Self> {
+        if allowed.line.hits > 0 {
+            return None
+        }
+        Some(if allowed.line.reached_without_hit == 0 { Self::PerfectGame } else { Self::NoHitter })
+    }
+}
+
+/// What one side's pitchers have allowed so far
+#[derive(Clone, Default)]
+struct Allowed {
+    line: GameLine,
+    pitchers: Vec<PersonId>,
+}
+
+impl Allowed {
+    fn add(&mut self, play: &Play) {
+        if !self.pitchers.contains(&play.matchup.pitcher.id) {
+            self.pitchers.push(play.matchup.pitcher.id);
+        }
+        self.line += GameLine::from_play(play);
+    }
+}
+
+impl Display for MasterpieceKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::PerfectGame => "Perfect Game",
+            Self::NoHitter => "No-Hitter",
+        })
+    }
+}
+
+#[derive(Clone)]
+enum AlertKind {
+    Watch { through: Inning },
+    BrokenUp { half: InningHalf, inning: Inning, description: String },
+}
+
+#[derive(Clone)]
+pub struct NoHitterAlert {
+    team_abbreviation: String,
+    masterpiece: MasterpieceKind,
+    kind: AlertKind,
+    pitcher_names: Vec<String>,
+    walks: CountingStat,
+    strikeouts: CountingStat,
+    pitches: CountingStat,
+    score: Score,
+}
+
+impl Display for NoHitterAlert {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Self { team_abbreviation, masterpiece, kind, pitcher_names, walks, strikeouts, pitches, score } = self;
+        match kind {
+            AlertKind::Watch { through } => {
+                writeln!(f, "## :rotating_light: {team_abbreviation} {masterpiece} Watch :rotating_light:")?;
+                writeln!(f, "{pitcher_names} through **{through}**", pitcher_names = pitcher_names.join(", "), through = nth(**through))?;
+            },
+            AlertKind::BrokenUp { half, inning, description } => {
+                writeln!(f, "## {team_abbreviation} {masterpiece} Broken Up")?;
+                writeln!(f, "{half} **{inning}**: {description}", half = half.three_char(), inning = nth(**inning))?;
+            },
+        }
+        writeln!(f, "> **{walks}** BB | **{strikeouts}** K")?;
+        writeln!(f, "> Pitch Count: **{pitches}**")?;
+        writeln!(f, "{score}", score = score.code_block())?;
+        Ok(())
+    }
+}
+
+impl Post for NoHitterAlert {}
+
+/// Tracks both teams' pitching, since rooting against a no-hitter is just as worth posting about
+pub struct NoHitterWatch {
+    /// The inning a no-hitter has to last through before it's posted
+    from_inning: usize,
+    /// What was last posted about for each pitching side
+    alerted: HomeAway<Option<MasterpieceKind>>,
+    /// By pitching side
+    allowed: HomeAway<Allowed>,
+}
+
+impl NoHitterWatch {
+    pub fn new(from_inning: usize) -> Self {
+        Self {
+            from_inning,
+            alerted: HomeAway::new(None, None),
+            allowed: HomeAway::new(Allowed::default(), Allowed::default()),
+        }
+    }
+
+    /// Checks the pitching team after each play, returning an alert at the end of an inning or when the bid ends.
+    pub fn update(&mut self, play: &Play, boxscore_teams: HomeAway<&TeamWithGameData>, abbreviations: HomeAway<&str>) -> Option<NoHitterAlert> {
+        let pitching_side = !play.about.inning_half.bats();
+        let team = boxscore_teams.choose(pitching_side);
+        let allowed = self.allowed.as_mut().choose(pitching_side);
+        allowed.add(play);
+        let current = MasterpieceKind::of(allowed);
+        let alerted = *self.alerted.as_ref().choose(pitching_side);
+
+        let (masterpiece, kind) = match (alerted, current) {
+            (Some(previous), current) if current != Some(previous) => {
+                // a perfect game turning into a no-hitter is still worth watching
+                *self.alerted.as_mut().choose(pitching_side) = current;
+                let description = play.result.completed_play_details.as_ref().map_or_else(String::new, |details| details.description.clone());
+                (previous, AlertKind::BrokenUp { half: play.about.inning_half, inning: play.about.inning, description })
+            },
+            (_, Some(current)) if play.count.outs == 3 && *play.about.inning >= self.from_inning => {
+                *self.alerted.as_mut().choose(pitching_side) = Some(current);
+                (current, AlertKind::Watch { through: play.about.inning })
+            },
+            _ => return None,
+        };
+
+        Some(NoHitterAlert {
+            team_abbreviation: abbreviations.choose(pitching_side).to_owned(),
+            masterpiece,
+            kind,
+            pitcher_names: allowed.pitchers.iter().filter_map(|id| team.players.get(id)).map(|player| player.boxscore_name.clone()).collect(),
+            walks: allowed.line.walks,
+            strikeouts: allowed.line.strikeouts,
+            pitches: allowed.line.pitches,
+            score: Score::new(
+                abbreviations.away.to_owned(),
+                play.result.away_score,
+                abbreviations.home.to_owned(),
+                play.result.home_score,
+                0,
+                play.about.inning_half.bats(),
+                BoldingDisplayKind::None,
+                BoldingDisplayKind::None,
+            ),
+        })
+    }
+}
@@ -0,0 +1,135 @@
+use crate::posts::Post;
+use crate::util::nth;
+use crate::util::statsapi::{GameLine, HalfInning};
+use fxhash::{FxHashMap, FxHashSet};
+use mlb_api::game::{Boxscore, Inning, InningHalf, Play};
+use mlb_api::person::PersonId;
+use mlb_api::single_stat;
+use mlb_api::stats::CountingStat;
+use mlb_api::HomeAway;
+use std::fmt::{Display, Formatter};
+use tokio::task::JoinSet;
+
+/// Strikeouts in a game that get called out
+const STRIKEOUT_THRESHOLDS: &[CountingStat] = &[10, 12, 15];
+/// Career strikeouts are called out every this many
+const STRIKEOUT_MILESTONE: CountingStat = 1000;
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+enum Callout {
+    ImmaculateInning,
+    Strikeouts(CountingStat),
+    Milestone(CountingStat),
+}
+
+impl Display for Callout {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ImmaculateInning => write!(f, "Immaculate Inning"),
+            Self::Strikeouts(strikeouts) => write!(f, "{strikeouts} Strikeouts"),
+            Self::Milestone(total) => write!(f, "Career Strikeout #{total}"),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct PitcherCallout {
+    team_abbreviation: String,
+    name: String,
+    callout: Callout,
+    half: InningHalf,
+    inning: Inning,
+    strikeouts: CountingStat,
+    pitches: CountingStat,
+}
+
+impl Display for PitcherCallout {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Self { team_abbreviation, name, callout, half, inning, strikeouts, pitches } = self;
+        writeln!(f, "## :fire: {team_abbreviation} {name}: {callout} :fire:")?;
+        writeln!(f, "{half} **{inning}**", half = half.three_char(), inning = nth(**inning))?;
+        writeln!(f, "> **{strikeouts}** K | Pitch Count: **{pitches}**")?;
+        Ok(())
+    }
+}
+
+impl Post for PitcherCallout {}
+
+pub struct PitcherWatch {
+    /// Career strikeouts before this game, only for the pitchers on the roster at the start
+    careers: FxHashMap<PersonId, CountingStat>,
+    today: FxHashMap<PersonId, GameLine>,
+    posted: FxHashSet<(PersonId, Callout)>,
+}
+
+impl PitcherWatch {
+    /// Fetches career totals for both pitching staffs at once; a pitcher whose stats can't be fetched just won't get milestone callouts.
+    pub async fn new(boxscore: &Boxscore) -> Self {
+        let mut requests = JoinSet::new();
+        for team in [&boxscore.teams.away, &boxscore.teams.home] {
+            for &id in team.pitchers.iter().chain(&team.bullpen) {
+                requests.spawn(async move { (id, single_stat!(Career + Pitching for id).await) });
+            }
+        }
+        let mut careers = FxHashMap::default();
+        while let Some(result) = requests.join_next().await {
+            let Ok((id, Ok(stats))) = result else { continue };
+            careers.insert(id, stats.strikeouts.unwrap_or_default());
+        }
+        Self {
+            careers,
+            today: FxHashMap::default(),
+            posted: FxHashSet::default(),
+        }
+    }
+
+    /// Pitches thrown so far by `id`, through the last play passed to [`Self::update`]
+    pub fn pitches(&self, id: PersonId) -> CountingStat {
+        self.today.get(&id).map_or(0, |line| line.pitches)
+    }
+
+    /// Checks the pitcher after each play, once it's been added to `half_inning`; each callout is only posted once per pitcher.
+    pub fn update(&mut self, play: &Play, half_inning: &HalfInning, boxscore: &Boxscore, abbreviations: HomeAway<&str>) -> Vec<PitcherCallout> {
+        let pitching_side = !play.about.inning_half.bats();
+        let team = boxscore.teams.as_ref().choose(pitching_side);
+        let id = play.matchup.pitcher.id;
+        let Some(pitcher) = team.players.get(&id) else { return Vec::new() };
+
+        let line = self.today.entry(id).or_default();
+        *line += GameLine::from_play(play);
+        let line = *line;
+        let strikeouts = line.strikeouts;
+
+        let mut callouts = Vec::new();
+
+        if play.count.outs == 3 && half_inning.is_immaculate() {
+            callouts.push(Callout::ImmaculateInning);
+        }
+
+        if let Some(&threshold) = STRIKEOUT_THRESHOLDS.iter().rev().find(|&&threshold| strikeouts >= threshold) {
+            callouts.push(Callout::Strikeouts(threshold));
+        }
+        if let Some(&before) = self.careers.get(&id) {
+            let total = before + strikeouts;
+            // only the milestone reached today, not one from a previous game
+            if total / STRIKEOUT_MILESTONE > before / STRIKEOUT_MILESTONE {
+                callouts.push(Callout::Milestone(total / STRIKEOUT_MILESTONE * STRIKEOUT_MILESTONE));
+            }
+        }
+
+        callouts
+            .into_iter()
+            // immaculate innings can happen more than once
+            .filter(|&callout| callout == Callout::ImmaculateInning || self.posted.insert((id, callout)))
+            .map(|callout| PitcherCallout {
+                team_abbreviation: abbreviations.choose(pitching_side).to_owned(),
+                name: pitcher.boxscore_name.clone(),
+                callout,
+                half: play.about.inning_half,
+                inning: play.about.inning,
+                strikeouts: line.strikeouts,
+                pitches: line.pitches,
+            })
+            .collect()
+    }
+}
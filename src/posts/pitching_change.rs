@@ -0,0 +1,68 @@
+use crate::posts::pitching_line::PitcherFinalLine;
+use crate::posts::Post;
+use crate::util::nth;
+use crate::util::statsapi::{BaseOutState, Score};
+use mlb_api::game::{Inning, InningHalf, PlayerWithGameData};
+use mlb_api::stats::TwoDecimalPlaceRateStat;
+use mlb_api::stats::derived::era;
+use mlb_api::Handedness;
+use std::fmt::{Display, Formatter};
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum PitchingChangePosts {
+    /// Only when the probable starter leaves the game
+    StarterOnly,
+    OurTeam,
+    BothTeams,
+}
+
+#[derive(Clone)]
+pub struct PitchingChange {
+    team_abbreviation: String,
+    outgoing: PitcherFinalLine,
+    incoming_name: String,
+    incoming_handedness: Handedness,
+    incoming_era: TwoDecimalPlaceRateStat,
+    score: Score,
+    inning: Inning,
+    half: InningHalf,
+    state: BaseOutState,
+}
+
+impl PitchingChange {
+    pub fn new(
+        team_abbreviation: &str,
+        outgoing: &PlayerWithGameData,
+        incoming: &PlayerWithGameData,
+        incoming_handedness: Handedness,
+        score: Score,
+        inning: Inning,
+        half: InningHalf,
+        state: BaseOutState,
+    ) -> Self {
+        Self {
+            team_abbreviation: team_abbreviation.to_owned(),
+            outgoing: PitcherFinalLine::from_play(outgoing),
+            incoming_name: incoming.boxscore_name.clone(),
+            incoming_handedness,
+            incoming_era: era(incoming.season_stats.pitching.earned_runs, incoming.season_stats.pitching.innings_pitched),
+            score,
+            inning,
+            half,
+            state,
+        }
+    }
+}
+
+impl Display for PitchingChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Self { team_abbreviation, outgoing, incoming_name, incoming_handedness, incoming_era, score, inning, half, state } = self;
+        writeln!(f, "## {team_abbreviation} Pitching Change")?;
+        write!(f, "{outgoing}")?;
+        writeln!(f, "**Now Pitching**: `{incoming_handedness}` | {incoming_name} ({incoming_era} ERA)")?;
+        writeln!(f, "{score} | {half} **{inning}**, {state}", score = score.code_block(), half = half.three_char(), inning = nth(**inning))?;
+        Ok(())
+    }
+}
+
+impl Post for PitchingChange {}
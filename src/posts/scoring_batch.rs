@@ -0,0 +1,127 @@
+use crate::posts::scoring_play::ScoringPlay;
+use crate::posts::scoring_play_event::ScoringPlayEvent;
+use crate::posts::Post;
+use crate::util::nth;
+use crate::util::statsapi::Score;
+use mlb_api::game::{Inning, InningHalf};
+use std::fmt::{Display, Formatter};
+use std::time::{Duration, Instant};
+
+#[derive(Clone)]
+pub enum BatchedPlay {
+    Play(ScoringPlay),
+    Event(ScoringPlayEvent),
+}
+
+impl BatchedPlay {
+    fn runs(&self) -> usize {
+        match self {
+            Self::Play(play) => play.runs(),
+            Self::Event(event) => event.runs(),
+        }
+    }
+
+    fn score(&self) -> &Score {
+        match self {
+            Self::Play(play) => play.score(),
+            Self::Event(event) => event.score(),
+        }
+    }
+}
+
+impl From<ScoringPlay> for BatchedPlay {
+    fn from(play: ScoringPlay) -> Self {
+        Self::Play(play)
+    }
+}
+
+impl From<ScoringPlayEvent> for BatchedPlay {
+    fn from(event: ScoringPlayEvent) -> Self {
+        Self::Event(event)
+    }
+}
+
+impl Display for BatchedPlay {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Play(play) => write!(f, "{play}"),
+            Self::Event(event) => write!(f, "{event}"),
+        }
+    }
+}
+
+/// Scoring plays from the same half inning, sent together
+#[derive(Clone)]
+pub struct ScoringBatch {
+    team_abbreviation: String,
+    half: InningHalf,
+    inning: Inning,
+    /// Never empty
+    plays: Vec<BatchedPlay>,
+}
+
+impl Display for ScoringBatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Self { team_abbreviation, half, inning, plays } = self;
+        let Some(last) = plays.last() else { return Ok(()) };
+        if let [play] = plays.as_slice() {
+            return write!(f, "{play}")
+        }
+        let runs = plays.iter().map(BatchedPlay::runs).sum::<usize>();
+        writeln!(f, "## {team_abbreviation} {runs}-run {half} {inning}", half = half.three_char(), inning = nth(**inning))?;
+        for play in plays {
+            writeln!(f, "{play}")?;
+        }
+        write!(f, "{score}", score = last.score().code_block())
+    }
+}
+
+impl Post for ScoringBatch {}
+
+pub struct ScoringBatcher {
+    /// `None` to send every play right away
+    quiet_period: Option<Duration>,
+    batch: Option<(ScoringBatch, Instant)>,
+}
+
+impl ScoringBatcher {
+    pub fn new(quiet_period: Option<Duration>) -> Self {
+        Self {
+            quiet_period,
+            batch: None,
+        }
+    }
+
+    /// Returns whatever is ready to send: the play itself when batching is off, or an earlier batch from a different half inning.
+    pub fn add(&mut self, team_abbreviation: &str, half: InningHalf, inning: Inning, play: impl Into<BatchedPlay>) -> Option<ScoringBatch> {
+        let batch = ScoringBatch {
+            team_abbreviation: team_abbreviation.to_owned(),
+            half,
+            inning,
+            plays: vec![play.into()],
+        };
+        if self.quiet_period.is_none() {
+            return Some(batch)
+        }
+        match &mut self.batch {
+            Some((existing, last_added)) if existing.half == half && existing.inning == inning => {
+                existing.plays.extend(batch.plays);
+                *last_added = Instant::now();
+                None
+            },
+            existing => existing.replace((batch, Instant::now())).map(|(batch, _)| batch),
+        }
+    }
+
+    /// The batch, if no run has scored for the quiet period
+    pub fn poll(&mut self) -> Option<ScoringBatch> {
+        let quiet_period = self.quiet_period?;
+        let (_, last_added) = self.batch.as_ref()?;
+        if last_added.elapsed() >= quiet_period { self.flush() } else { None }
+    }
+
+    /// At the end of the half inning or the game
+    pub fn flush(&mut self) -> Option<ScoringBatch> {
+        self.batch.take().map(|(batch, _)| batch)
+    }
+}
@@ -1,12 +1,14 @@
 use crate::util::nth;
-use crate::util::statsapi::{BoldingDisplayKind, Score, ScoredRunner};
+use crate::util::statsapi::{BoldingDisplayKind, Score, ScoreChange, ScoredRunner};
 use anyhow::{Context, Result};
 use mlb_api::game::{Inning, InningHalf, Play};
-use mlb_api::meta::EventType;
+use mlb_api::meta::{EventType, HitTrajectory};
+use mlb_api::HomeAway;
 use std::fmt::{Debug, Display, Formatter};
 use fxhash::FxHashMap;
 use mlb_api::person::{Ballplayer, PersonId};
 use crate::posts::Post;
+use crate::components::batted_ball::{self, BattedBall};
 
 #[derive(Clone)]
 pub struct ScoringPlay {
@@ -17,6 +19,11 @@ pub struct ScoringPlay {
     rbi: usize,
     scores: Vec<ScoredRunner>,
     event: EventType,
+    /// Everything but home runs, which depend on how many runners scored, see [`Self::label`]
+    label: String,
+    batted_ball: Option<BattedBall>,
+    change: Option<ScoreChange>,
+    is_walkoff: bool,
 }
 
 impl ScoringPlay {
@@ -24,25 +31,68 @@ impl ScoringPlay {
         play: &Play,
         home_abbreviation: &str,
         away_abbreviation: &str,
+        previous_score: HomeAway<usize>,
         all_players: &FxHashMap<PersonId, Ballplayer<()>>,
     ) -> Result<Self> {
         let is_walkoff = play.about.inning_half == InningHalf::Bottom && *play.about.inning >= 9 && play.result.home_score > play.result.away_score;
         let details = play.result.completed_play_details.as_ref().context("Expected play to be complete")?;
 
+        let score = Score::new(away_abbreviation.to_owned(), play.result.away_score, home_abbreviation.to_owned(), play.result.home_score, 0, play.about.inning_half.bats(), BoldingDisplayKind::MostRecentlyScored, if is_walkoff { BoldingDisplayKind::WinningTeam } else { BoldingDisplayKind::None });
+        let scores = ScoredRunner::from_description(&details.description, all_players);
+
         Ok(Self {
+            change: ScoreChange::classify(previous_score, &score, play.about.inning_half.bats()),
             inning: play.about.inning,
             half: play.about.inning_half,
             outs: play.count.outs,
-            score: Score::new(away_abbreviation.to_owned(), play.result.away_score, home_abbreviation.to_owned(), play.result.home_score, 0, play.about.inning_half.bats(), BoldingDisplayKind::MostRecentlyScored, if is_walkoff { BoldingDisplayKind::WinningTeam } else { BoldingDisplayKind::None }),
+            score,
             rbi: details.rbi,
-            scores: ScoredRunner::from_description(&details.description, all_players),
-            event: details.event
+            scores,
+            event: details.event,
+            label: rbi_label(
+                details.event,
+                batted_ball::trajectory(play),
+                details.rbi,
+                is_infield_hit(&details.description),
+                details.description.contains("error"),
+            ),
+            batted_ball: BattedBall::from_play(play),
+            is_walkoff,
         })
     }
 
+    pub fn event(&self) -> EventType {
+        self.event
+    }
+
+    pub fn score(&self) -> &Score {
+        &self.score
+    }
+
+    pub fn runs(&self) -> usize {
+        self.scores.len()
+    }
+
+    pub fn is_walkoff(&self) -> bool {
+        self.is_walkoff
+    }
+
     pub fn as_one_liner(&self) -> OneLiner {
         OneLiner(self)
     }
+
+    /// Home runs are bolded unless they're inside-the-park, ex: "**2HR**"
+    fn label(&self) -> String {
+        if self.event != EventType::HomeRun {
+            return self.label.clone()
+        }
+        let home_run = if self.scores.len() == 1 { "HR".to_owned() } else { format!("{}HR", self.scores.len()) };
+        if self.scores.iter().any(|score| score.play().contains("inside-the-park")) {
+            home_run
+        } else {
+            format!("**{home_run}**")
+        }
+    }
 }
 
 #[must_use]
@@ -52,7 +102,7 @@ impl Display for OneLiner<'_> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         use std::fmt::Write;
 
-        write!(f, "{score} | {half} **{inning}**:", score = self.0.score.code_block(), half = self.0.half.three_char(), inning = nth(*self.0.inning))?;
+        write!(f, "{score} | {half} **{inning}** ({label}):", score = self.0.score.code_block(), half = self.0.half.three_char(), inning = nth(*self.0.inning), label = self.0.label())?;
         for score in &self.0.scores {
             write!(f, " {score}")?;
         }
@@ -65,76 +115,42 @@ impl Display for ScoringPlay {
         let Self { score, .. } = self;
         let half = self.half.three_char();
         let inning = nth(*self.inning);
-        write!(f, "`{score}` | {half} **{inning}**:")?;
+        write!(f, "`{score}` | {half} **{inning}** ({label})", label = self.label())?;
+        if let Some(change) = self.change {
+            write!(f, " *|* __{change}__")?;
+        }
+        write!(f, ":")?;
         for score in &self.scores {
             write!(f, " {score}")?;
         }
+        if let Some(batted_ball) = &self.batted_ball {
+            write!(f, "\n{batted_ball}")?;
+        }
         Ok(())
     }
 }
 
 impl Debug for ScoringPlay {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{score:?} (", score = self.score)?;
-        match self.event {
-            EventType::HomeRun => if self.scores.iter().any(|score| score.play().contains("inside-the-park")) {
-                if self.scores.len() == 1 {
-                    write!(f, "HR")?
-                } else {
-                    write!(f, "{}HR", self.scores.len())?
-                }
-            } else {
-                if self.scores.len() == 1 {
-                    write!(f, "**HR**")?
-                } else {
-                    write!(f, "**{}HR**", self.scores.len())?
-                }
-            },
-            EventType::FieldOut => {
-                if self.rbi == 1 {
-                    write!(f, "RBI groundout")?
-                } else {
-                    write!(f, "{n}RBI groundout", n = self.rbi)?
-                }
-            }
-            EventType::ForceOut => {
-                if self.rbi == 1 {
-                    write!(f, "RBI forceout")?
-                } else {
-                    write!(f, "{n}RBI forceout", n = self.rbi)?
-                }
-            }
-            EventType::FieldersChoiceFieldOut => {
-                if self.rbi == 1 {
-                    write!(f, "RBI Fielder's choice")?
-                } else {
-                    write!(f, "{n}RBI Fielder's choice", n = self.rbi)?
-                }
-            }
-            EventType::FieldError => write!(f, "Error")?,
-            EventType::IntentionalWalk => write!(f, "Bases loaded intentional walk")?,
-            EventType::Walk => write!(f, "Bases loaded walk")?,
-            EventType::HitByPitch => write!(f, "Bases loaded HBP")?,
-            event => {
-                if self.rbi == 1 {
-                    write!(f, "RBI {event}", event = event.to_string().to_ascii_lowercase())?
-                } else {
-                    write!(f, "{n}RBI {event}", n = self.rbi, event = event.to_string().to_ascii_lowercase())?
-                }
-            },
+        write!(f, "{score:?} ({label})", score = self.score, label = self.label())?;
+        if let Some(change) = self.change {
+            write!(f, " *|* __{change}__")?;
         }
-        writeln!(f, ")")?;
+        writeln!(f)?;
         writeln!(
             f,
             "{half} **{inning}**, **{outs}** out{out_suffix}.",
-            half = if self.top { "Top" } else { "Bot" },
-            inning = nth(self.inning as usize),
+            half = self.half.three_char(),
+            inning = nth(*self.inning),
             outs = self.outs,
             out_suffix = if self.outs == 1 { "" } else { "s" }
         )?;
         for score in &self.scores {
             writeln!(f, "{score:?}")?
         }
+        if let Some(batted_ball) = &self.batted_ball {
+            writeln!(f, "{batted_ball}")?;
+        }
 
         write!(f, "\n")?;
 
@@ -142,4 +158,115 @@ impl Debug for ScoringPlay {
     }
 }
 
-impl Post for ScoringPlay {}
\ No newline at end of file
+impl Post for ScoringPlay {}
+
+/// Whether the ball was first fielded by an infielder, from the batter's part of the description since later clauses can name
+/// infielders on relays and plays at the plate. The hit location would be exact, but the pinned `HitData` doesn't deserialize it.
+fn is_infield_hit(description: &str) -> bool {
+    // the clause is searched from the hit itself, since the batter's name can have periods in it (ex: "J.P. Crawford")
+    let hit = [" singles on ", " doubles on ", " triples on "]
+        .iter()
+        .filter_map(|verb| description.find(verb))
+        .min()
+        .map_or(description, |start| &description[start..]);
+    let batter_clause = hit.split_once(". ").map_or(hit, |(batter_clause, _)| batter_clause);
+    ["to pitcher", "to catcher", "to first baseman", "to second baseman", "to third baseman", "to shortstop"]
+        .iter()
+        .any(|fielder| batter_clause.contains(fielder))
+}
+
+/// The label for a run-scoring plate appearance that isn't a home run, ex: "Sac fly", "RBI double play", "2RBI infield single".
+/// `trajectory` is the batted ball's; `with_error` is whether an error was made on the play.
+pub fn rbi_label(event: EventType, trajectory: Option<HitTrajectory>, rbi: usize, is_infield: bool, with_error: bool) -> String {
+    let error_suffix = if with_error { ", error" } else { "" };
+    let label = match event {
+        EventType::SacrificeFly => return format!("Sac fly{error_suffix}"),
+        EventType::SacrificeBunt => return format!("Sac bunt{error_suffix}"),
+        EventType::SacrificeFlyDoublePlay => return "Sac fly double play".to_owned(),
+        EventType::SacrificeBuntDoublePlay => return "Sac bunt double play".to_owned(),
+        EventType::FieldError => return "Error".to_owned(),
+        EventType::IntentionalWalk => return "Bases loaded intentional walk".to_owned(),
+        EventType::Walk => return "Bases loaded walk".to_owned(),
+        EventType::HitByPitch => return "Bases loaded HBP".to_owned(),
+        EventType::CatchersInterference => return "Catcher's interference".to_owned(),
+        EventType::GroundedIntoDoublePlay | EventType::DoublePlay => "double play".to_owned(),
+        EventType::TriplePlay => "triple play".to_owned(),
+        EventType::FieldOut => match trajectory {
+            Some(HitTrajectory::FlyBall) => "flyout",
+            Some(HitTrajectory::LineDrive | HitTrajectory::BuntLineDrive) => "lineout",
+            Some(HitTrajectory::Popup | HitTrajectory::BuntPopup) => "pop out",
+            Some(HitTrajectory::BuntGrounder) => "bunt groundout",
+            Some(HitTrajectory::GroundBall) | None => "groundout",
+        }.to_owned(),
+        EventType::ForceOut => format!("forceout{error_suffix}"),
+        EventType::FieldersChoice | EventType::FieldersChoiceFieldOut => format!("fielder's choice{error_suffix}"),
+        EventType::Single if is_infield => format!("infield single{error_suffix}"),
+        event => format!("{event}{error_suffix}", event = event.to_string().to_ascii_lowercase()),
+    };
+    match rbi {
+        0 => capitalize(&label),
+        1 => format!("RBI {label}"),
+        n => format!("{n}RBI {label}"),
+    }
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    chars.next().map_or_else(String::new, |first| first.to_uppercase().chain(chars).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sac_fly() {
+        assert_eq!(rbi_label(EventType::SacrificeFly, Some(HitTrajectory::FlyBall), 1, false, false), "Sac fly");
+    }
+
+    #[test]
+    fn sac_bunt() {
+        assert_eq!(rbi_label(EventType::SacrificeBunt, Some(HitTrajectory::BuntGrounder), 1, true, false), "Sac bunt");
+    }
+
+    #[test]
+    fn sac_bunt_error() {
+        assert_eq!(rbi_label(EventType::SacrificeBunt, Some(HitTrajectory::BuntGrounder), 1, true, true), "Sac bunt, error");
+    }
+
+    #[test]
+    fn rbi_double_play() {
+        assert_eq!(rbi_label(EventType::GroundedIntoDoublePlay, Some(HitTrajectory::GroundBall), 1, true, false), "RBI double play");
+    }
+
+    #[test]
+    fn rbi_infield_single() {
+        assert_eq!(rbi_label(EventType::Single, Some(HitTrajectory::GroundBall), 1, true, false), "RBI infield single");
+    }
+
+    #[test]
+    fn field_out_by_trajectory() {
+        assert_eq!(rbi_label(EventType::FieldOut, Some(HitTrajectory::FlyBall), 1, false, false), "RBI flyout");
+        assert_eq!(rbi_label(EventType::FieldOut, Some(HitTrajectory::LineDrive), 1, false, false), "RBI lineout");
+        assert_eq!(rbi_label(EventType::FieldOut, Some(HitTrajectory::Popup), 1, true, false), "RBI pop out");
+    }
+
+    #[test]
+    fn capitalized_without_rbi() {
+        assert_eq!(rbi_label(EventType::GroundedIntoDoublePlay, Some(HitTrajectory::GroundBall), 0, true, false), "Double play");
+        assert_eq!(rbi_label(EventType::FieldOut, Some(HitTrajectory::GroundBall), 0, true, false), "Groundout");
+    }
+
+    #[test]
+    fn infield_hit_ignores_relays() {
+        let description = "Bo Bichette singles on a line drive to left fielder Aaron Judge. George Springer scores. Daulton Varsho out at home, left fielder Aaron Judge to catcher Jose Trevino.";
+        assert!(!is_infield_hit(description));
+        assert!(is_infield_hit("Bo Bichette singles on a ground ball to shortstop Anthony Volpe. George Springer scores."));
+    }
+
+    #[test]
+    fn infield_hit_by_initialed_batter() {
+        assert!(is_infield_hit("J.P. Crawford singles on a ground ball to second baseman Jose Altuve. Julio Rodriguez scores."));
+        assert!(!is_infield_hit("J.P. Crawford singles on a line drive to left fielder Yordan Alvarez. Julio Rodriguez scores. Cal Raleigh out at home, left fielder Yordan Alvarez to catcher Yainer Diaz."));
+    }
+}
@@ -1,8 +1,9 @@
 use crate::util::nth;
-use crate::util::statsapi::{BoldingDisplayKind, Score, ScoredRunner};
+use crate::util::statsapi::{BoldingDisplayKind, Score, ScoreChange, ScoredRunner};
 use fxhash::FxHashMap;
 use mlb_api::game::{ActionPlayDetails, Inning, InningHalf, Play, PlayEventCommon};
 use mlb_api::meta::EventType;
+use mlb_api::HomeAway;
 use mlb_api::person::{Ballplayer, PersonId};
 use std::fmt::{Debug, Display, Formatter};
 use crate::posts::Post;
@@ -15,6 +16,8 @@ pub struct ScoringPlayEvent {
     half: InningHalf,
     scores: Vec<ScoredRunner>,
     event: EventType,
+    change: Option<ScoreChange>,
+    is_walkoff: bool,
 }
 
 impl ScoringPlayEvent {
@@ -23,6 +26,7 @@ impl ScoringPlayEvent {
         play: &Play,
         home_abbreviation: &str,
         away_abbreviation: &str,
+        previous_score: HomeAway<usize>,
         all_player_names: &FxHashMap<PersonId, Ballplayer<()>>,
         event: EventType,
     ) -> Self {
@@ -30,16 +34,33 @@ impl ScoringPlayEvent {
         let away_score = details.away_score;
         let is_walkoff = play.about.inning_half == InningHalf::Bottom && *play.about.inning >= 9 && home_score > away_score;
 
+        let score = Score::new(away_abbreviation.to_owned(), details.away_score, home_abbreviation.to_owned(), details.home_score, 0, play.about.inning_half.bats(), BoldingDisplayKind::MostRecentlyScored, if is_walkoff { BoldingDisplayKind::WinningTeam } else { BoldingDisplayKind::None });
+        let scores = ScoredRunner::from_description(&details.description, all_player_names);
+
         Self {
+            change: ScoreChange::classify(previous_score, &score, play.about.inning_half.bats()),
             inning: play.about.inning,
             outs: play.count.outs,
             half: play.about.inning_half,
-            score: Score::new(away_abbreviation.to_owned(), details.away_score, home_abbreviation.to_owned(), details.home_score, 0, play.about.inning_half.bats(), BoldingDisplayKind::MostRecentlyScored, if is_walkoff { BoldingDisplayKind::WinningTeam } else { BoldingDisplayKind::None }),
-            scores: ScoredRunner::from_description(&details.description, all_player_names),
+            score,
+            scores,
             event,
+            is_walkoff,
         }
     }
 
+    pub fn score(&self) -> &Score {
+        &self.score
+    }
+
+    pub fn runs(&self) -> usize {
+        self.scores.len()
+    }
+
+    pub fn is_walkoff(&self) -> bool {
+        self.is_walkoff
+    }
+
     pub fn as_one_liner(&self) -> OneLiner {
         OneLiner(self)
         
@@ -53,8 +74,8 @@ impl Display for OneLiner<'_> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         use std::fmt::Write;
 
-        let ScoringPlayEvent { score, inning, half, scores, .. } = self.0;
-        write!(f, "{score} | {half} **{inning}**:", score = score.code_block(), half = half.three_char(), inning = nth(**inning))?;
+        let ScoringPlayEvent { score, inning, half, scores, event, .. } = self.0;
+        write!(f, "{score} | {half} **{inning}** ({label}):", score = score.code_block(), half = half.three_char(), inning = nth(**inning), label = event_label(*event))?;
         for score in scores {
             write!(f, " {score}")?;
         }
@@ -64,7 +85,11 @@ impl Display for OneLiner<'_> {
 
 impl Debug for ScoringPlayEvent {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        writeln!(f, "{score:?} ({event})", score = self.score, event = self.event)?;
+        write!(f, "{score:?} ({label})", score = self.score, label = event_label(self.event))?;
+        if let Some(change) = self.change {
+            write!(f, " *|* __{change}__")?;
+        }
+        writeln!(f)?;
         writeln!(
             f,
             "{half} **{inning}**, **{outs}** out{out_suffix}.",
@@ -85,10 +110,14 @@ impl Debug for ScoringPlayEvent {
 
 impl Display for ScoringPlayEvent {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let Self { score, inning, half, scores, .. } = self;
+        let Self { score, inning, half, scores, event, change, .. } = self;
         let half = half.three_char();
         let inning = nth(**inning);
-        write!(f, "`{score}` | {half} **{inning}**:")?;
+        write!(f, "`{score}` | {half} **{inning}** ({label})", label = event_label(*event))?;
+        if let Some(change) = change {
+            write!(f, " *|* __{change}__")?;
+        }
+        write!(f, ":")?;
         for score in scores {
             write!(f, " {score}")?;
         }
@@ -97,3 +126,73 @@ impl Display for ScoringPlayEvent {
 }
 
 impl Post for ScoringPlayEvent {}
+
+/// Scoring events that happen during a plate appearance rather than ending one
+fn event_label(event: EventType) -> &'static str {
+    match event {
+        EventType::WildPitch => "Wild pitch",
+        EventType::PassedBall => "Passed ball",
+        EventType::Balk => "Balk",
+        EventType::DisengagementViolation => "Disengagement violation",
+        EventType::StolenBase | EventType::StolenBase2B | EventType::StolenBase3B => "Stolen base",
+        EventType::StolenBaseHome => "Steal of home",
+        EventType::CaughtStealing
+        | EventType::CaughtStealing2B
+        | EventType::CaughtStealing3B
+        | EventType::CaughtStealingHome
+        | EventType::CaughtStealingDoublePlay => "Caught stealing",
+        EventType::PickoffCaughtStealing2B | EventType::PickoffCaughtStealing3B | EventType::PickoffCaughtStealingHome => "Pickoff, caught stealing",
+        EventType::Pickoff1B | EventType::Pickoff2B | EventType::Pickoff3B => "Pickoff",
+        EventType::PickoffError1B | EventType::PickoffError2B | EventType::PickoffError3B => "Error on pickoff attempt",
+        EventType::DefensiveIndifference => "Defensive indifference",
+        EventType::CatchersInterference => "Catcher's interference",
+        EventType::FieldersInterference => "Fielder's interference",
+        EventType::BattersInterference => "Batter's interference",
+        EventType::RunnersInterference | EventType::RunnersInterferenceDoublePlay => "Runner's interference",
+        EventType::FanInterference => "Fan interference",
+        EventType::Error | EventType::FieldError => "Error",
+        EventType::OtherOut => "Out on the bases",
+        EventType::OtherAdvancement => "Runner advance",
+        // these end a plate appearance or don't move runners, so they aren't expected here
+        EventType::BatterTimeout
+        | EventType::MoundVisit
+        | EventType::GameAdvisory
+        | EventType::PitchingSubstitution
+        | EventType::DefensiveSwitch
+        | EventType::DefensiveSubstitution
+        | EventType::OffensiveSubstitution
+        | EventType::UmpireSubstitution
+        | EventType::PitcherStepOff
+        | EventType::BatterHandednessSwitch
+        | EventType::PitcherHandednessSwitch
+        | EventType::Ejection
+        | EventType::NoPitch
+        | EventType::FieldOut
+        | EventType::ForceOut
+        | EventType::FieldersChoice
+        | EventType::FieldersChoiceFieldOut
+        | EventType::Strikeout
+        | EventType::StrikeoutDoublePlay
+        | EventType::StrikeoutTriplePlay
+        | EventType::SacrificeBunt
+        | EventType::SacrificeFly
+        | EventType::GroundedIntoDoublePlay
+        | EventType::GroundedIntoTriplePlay
+        | EventType::DoublePlay
+        | EventType::TriplePlay
+        | EventType::SacrificeFlyDoublePlay
+        | EventType::SacrificeBuntDoublePlay
+        | EventType::Injury
+        | EventType::PriorRulingPending
+        | EventType::RulingPending
+        | EventType::AtBatStart
+        | EventType::RunnerPlaced
+        | EventType::Walk
+        | EventType::IntentionalWalk
+        | EventType::HitByPitch
+        | EventType::Single
+        | EventType::Double
+        | EventType::Triple
+        | EventType::HomeRun => "Runner advance",
+    }
+}
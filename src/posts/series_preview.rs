@@ -0,0 +1,38 @@
+use crate::components::pitching::ProbablePitcher;
+use crate::posts::Post;
+use chrono::DateTime;
+use chrono_tz::Tz;
+use mlb_api::HomeAway;
+use std::fmt::{Display, Formatter};
+
+#[derive(Clone)]
+pub struct SeriesPreview {
+    title: String,
+    games: Vec<(DateTime<Tz>, HomeAway<ProbablePitcher>)>,
+}
+
+impl SeriesPreview {
+    pub fn new(title: String, games: Vec<(DateTime<Tz>, HomeAway<ProbablePitcher>)>) -> Self {
+        Self {
+            title,
+            games,
+        }
+    }
+}
+
+impl Display for SeriesPreview {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Self { title, games } = self;
+        writeln!(f, "# Series Preview: {title} ({n} Games)", n = games.len())?;
+        for (idx, (datetime, pitchers)) in games.iter().enumerate() {
+            writeln!(f, "### __Game {n}__ (<t:{timestamp}:f>)", n = idx + 1, timestamp = datetime.timestamp())?;
+            writeln!(f, "{away_pitcher}", away_pitcher = pitchers.away)?;
+            writeln!(f, "{home_pitcher}", home_pitcher = pitchers.home)?;
+        }
+        write!(f, "> ")?;
+
+        Ok(())
+    }
+}
+
+impl Post for SeriesPreview {}
@@ -0,0 +1,104 @@
+use mlb_api::game::LiveFeedData;
+use mlb_api::meta::CodedGameState;
+use std::fmt::{Display, Formatter};
+use crate::posts::Post;
+
+/// The feed doesn't carry the makeup or resumption date, so postponements and suspensions only have their reason.
+#[derive(Clone, PartialEq, Eq)]
+pub enum GameStatus {
+    Scheduled,
+    DelayedStart { reason: Option<String> },
+    Delayed { reason: Option<String> },
+    Live,
+    Postponed { reason: Option<String> },
+    Suspended { reason: Option<String> },
+    Cancelled { reason: Option<String> },
+    Final,
+}
+
+impl GameStatus {
+    pub fn from_data(data: &LiveFeedData) -> Self {
+        let reason = data.status.reason.clone();
+        // delays keep the coded state of whatever they interrupted, only the detailed state tells them apart
+        let detailed_state = data.status.id.as_str();
+        match data.status.coded_game_state {
+            CodedGameState::Postponed => Self::Postponed { reason },
+            CodedGameState::Suspended => Self::Suspended { reason },
+            CodedGameState::Cancelled => Self::Cancelled { reason },
+            CodedGameState::Finished | CodedGameState::Forfeit | CodedGameState::Writing | CodedGameState::Unknown => Self::Final,
+            _ if detailed_state.starts_with("Delayed Start") => Self::DelayedStart { reason },
+            _ if detailed_state.starts_with("Delayed") => Self::Delayed { reason },
+            CodedGameState::InProgress | CodedGameState::ManagerChallenge | CodedGameState::UmpireReview => Self::Live,
+            CodedGameState::PreGame if detailed_state == "Warmup" => Self::Live,
+            CodedGameState::Scheduled | CodedGameState::PreGame => Self::Scheduled,
+        }
+    }
+
+    /// The game will not be played (today), so there is nothing left to wait on.
+    pub fn is_called_off(&self) -> bool {
+        matches!(self, Self::Postponed { .. } | Self::Cancelled { .. })
+    }
+}
+
+#[derive(Clone)]
+pub struct StatusChange {
+    title: String,
+    status: GameStatus,
+}
+
+impl Display for StatusChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Self { title, status } = self;
+        write!(f, "### {title} | ")?;
+        match status {
+            GameStatus::DelayedStart { reason: Some(reason) } => write!(f, "Delayed Start: {reason}")?,
+            GameStatus::DelayedStart { reason: None } => write!(f, "Delayed Start")?,
+            GameStatus::Delayed { reason: Some(reason) } => write!(f, "Delayed: {reason}")?,
+            GameStatus::Delayed { reason: None } => write!(f, "Delayed")?,
+            GameStatus::Postponed { reason: Some(reason) } => write!(f, "Postponed: {reason}")?,
+            GameStatus::Postponed { reason: None } => write!(f, "Postponed")?,
+            GameStatus::Suspended { reason: Some(reason) } => write!(f, "Suspended: {reason}")?,
+            GameStatus::Suspended { reason: None } => write!(f, "Suspended")?,
+            GameStatus::Cancelled { reason: Some(reason) } => write!(f, "Cancelled: {reason}")?,
+            GameStatus::Cancelled { reason: None } => write!(f, "Cancelled")?,
+            GameStatus::Live => write!(f, "Resumed")?,
+            GameStatus::Scheduled | GameStatus::Final => {},
+        }
+        Ok(())
+    }
+}
+
+impl Post for StatusChange {}
+
+pub struct StatusWatch {
+    title: String,
+    last: GameStatus,
+}
+
+impl StatusWatch {
+    pub fn new(title: String) -> Self {
+        Self {
+            title,
+            last: GameStatus::Scheduled,
+        }
+    }
+
+    /// Returns a post if the status changed in a way worth posting about
+    pub fn update(&mut self, status: GameStatus) -> Option<StatusChange> {
+        // a suspended game shows as scheduled again on the day it resumes, so that shouldn't count as a change
+        if status == self.last || status == GameStatus::Scheduled {
+            return None
+        }
+        let previous = core::mem::replace(&mut self.last, status.clone());
+        let should_post = match &status {
+            GameStatus::Scheduled | GameStatus::Final => false,
+            GameStatus::Live => matches!(previous, GameStatus::DelayedStart { .. } | GameStatus::Delayed { .. } | GameStatus::Suspended { .. }),
+            _ => true,
+        };
+        should_post.then(|| StatusChange { title: self.title.clone(), status })
+    }
+
+    pub fn status(&self) -> &GameStatus {
+        &self.last
+    }
+}
@@ -0,0 +1,54 @@
+use crate::components::batted_ball::BattedBall;
+use crate::components::linescore::LineScore;
+use crate::components::top_performers::Performance;
+use crate::posts::Post;
+use anyhow::Result;
+use mlb_api::game::{Boxscore, Linescore, Play};
+use mlb_api::meta::EventType;
+use mlb_api::team::Team;
+use mlb_api::HomeAway;
+use std::fmt::{Display, Formatter};
+
+#[derive(Clone)]
+pub struct Walkoff {
+    team_abbreviation: String,
+    event: EventType,
+    batter: Option<Performance>,
+    batted_ball: Option<BattedBall>,
+    linescore: LineScore,
+}
+
+impl Walkoff {
+    /// `event` is passed separately since walk-offs can also come from events during a plate appearance, like a wild pitch
+    pub fn new(play: &Play, event: EventType, boxscore: &Boxscore, linescore: &Linescore, teams: HomeAway<&Team<()>>) -> Result<Self> {
+        let is_plate_appearance = play.result.completed_play_details.as_ref().is_some_and(|details| details.event == event);
+        Ok(Self {
+            team_abbreviation: teams.home.name.abbreviation.clone(),
+            event,
+            batter: boxscore.teams.home.players.get(&play.matchup.batter.id).map(Performance::new),
+            batted_ball: if is_plate_appearance { BattedBall::from_play(play) } else { None },
+            linescore: LineScore::new(linescore, teams)?,
+        })
+    }
+}
+
+impl Display for Walkoff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Self { team_abbreviation, event, batter, batted_ball, linescore } = self;
+        let event = match event {
+            EventType::HomeRun => "HR".to_owned(),
+            event => event.to_string().to_ascii_uppercase(),
+        };
+        writeln!(f, "# :tada: {team_abbreviation} WALK-OFF {event}! :tada:")?;
+        if let Some(batter) = batter {
+            writeln!(f, "{batter}")?;
+        }
+        if let Some(batted_ball) = batted_ball {
+            writeln!(f, "{batted_ball}")?;
+        }
+        writeln!(f, "{linescore}")?;
+        Ok(())
+    }
+}
+
+impl Post for Walkoff {}
@@ -4,6 +4,7 @@ use ffi::set_cursor;
 pub mod ffi;
 pub mod statsapi;
 pub mod stat;
+pub mod polling;
 
 pub fn nth(n: usize) -> String {
     let mut buf = String::with_capacity(n.checked_ilog10().map_or(1, |x| x + 1) as usize + 2);
@@ -0,0 +1,130 @@
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use chrono::{NaiveDateTime, TimeDelta, Utc};
+use fxhash::FxHashSet;
+use mlb_api::game::{GameId, LiveFeedRequest, LiveFeedResponse, PlayStreamEvent};
+use mlb_api::request::{self, RequestURLBuilderExt};
+use crate::util::ffi;
+
+#[derive(Copy, Clone)]
+pub struct Backoff {
+    attempt: u32,
+}
+
+impl Backoff {
+    pub const BASE: Duration = Duration::from_secs(2);
+    pub const MAX: Duration = Duration::from_secs(120);
+
+    pub const fn new() -> Self {
+        Self { attempt: 0 }
+    }
+
+    pub fn next_delay(&mut self) -> Duration {
+        let delay = Self::BASE.saturating_mul(1 << self.attempt.min(16)).min(Self::MAX);
+        self.attempt = self.attempt.saturating_add(1);
+        delay
+    }
+
+    pub fn reset(&mut self) {
+        self.attempt = 0;
+    }
+
+    pub async fn wait(&mut self, error: &anyhow::Error) {
+        let delay = self.next_delay();
+        eprintln!("\nConnection error ({error}), retrying in {}s...", delay.as_secs());
+        tokio::time::sleep(delay).await;
+    }
+}
+
+/// Connection problems and server errors are worth retrying; a response that doesn't match the expected shape or an error from the API would just fail again.
+pub fn is_transient(error: &anyhow::Error) -> bool {
+    match error.downcast_ref::<request::Error>() {
+        Some(request::Error::Network(_)) => true,
+        // a body that isn't JSON at all is a 5xx error page or a response that got cut off
+        Some(request::Error::Serde(e)) => e.is_syntax() || e.is_eof(),
+        _ => false,
+    }
+}
+
+/// Retries `f` with exponential backoff while it fails with a [transient](is_transient) error, any other error is returned.
+pub async fn retry<T, F: Future<Output = Result<T>>>(mut f: impl FnMut() -> F) -> Result<T> {
+    let mut backoff = Backoff::new();
+    loop {
+        match f().await {
+            Ok(value) => return Ok(value),
+            Err(e) if is_transient(&e) => backoff.wait(&e).await,
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+pub async fn get_live_feed(id: GameId) -> Result<LiveFeedResponse> {
+    retry(|| async move { Ok(LiveFeedRequest::builder().id(id).build_and_get().await?) }).await
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+enum PlayKey {
+    EndPlay(usize),
+    PlayEvent(usize, usize),
+    GameEnd,
+}
+
+/// Restarting a [`PlayStream`](mlb_api::game::PlayStream) on a fresh feed replays every play in it, this filters out the ones already handled.
+#[derive(Default)]
+pub struct SeenPlays {
+    inner: FxHashSet<PlayKey>,
+}
+
+impl SeenPlays {
+    /// Returns `true` if the event has not been seen before
+    pub fn insert(&mut self, event: &PlayStreamEvent) -> bool {
+        let key = match event {
+            PlayStreamEvent::EndPlay(play) => PlayKey::EndPlay(play.about.at_bat_idx),
+            PlayStreamEvent::PlayEvent(play_event, play) => {
+                let Some(idx) = play.play_events.iter().position(|other| core::ptr::eq(other, *play_event)) else { return true };
+                PlayKey::PlayEvent(play.about.at_bat_idx, idx)
+            },
+            PlayStreamEvent::GameEnd(..) => PlayKey::GameEnd,
+            _ => return true,
+        };
+        self.inner.insert(key)
+    }
+}
+
+/// How often to check for a lineup, given how long until first pitch.
+///
+/// Lineups usually come out 3-5 hours before first pitch, so far away from that there's no point in polling often.
+pub fn pregame_poll_interval(until_first_pitch: TimeDelta, recommended_poll_rate: Duration) -> Duration {
+    let interval = if until_first_pitch > TimeDelta::hours(6) {
+        Duration::from_secs(30 * 60)
+    } else if until_first_pitch > TimeDelta::hours(5) {
+        Duration::from_secs(5 * 60)
+    } else if until_first_pitch > TimeDelta::hours(1) {
+        Duration::from_secs(60)
+    } else {
+        recommended_poll_rate
+    };
+    interval.max(recommended_poll_rate)
+}
+
+pub fn format_countdown(delta: TimeDelta) -> String {
+    let sign = if delta < TimeDelta::zero() { "-" } else { "" };
+    let seconds = delta.num_seconds().unsigned_abs();
+    format!("{sign}{hours}:{minutes:02}:{seconds:02}", hours = seconds / 3600, minutes = seconds / 60 % 60, seconds = seconds % 60)
+}
+
+/// Prints a countdown to `until` on the current line and returns once `interval` has passed.
+pub async fn countdown(until: NaiveDateTime, label: &str, interval: Duration) {
+    let next_poll = Instant::now() + interval;
+    while let Some(remaining) = next_poll.checked_duration_since(Instant::now()) {
+        print!(
+            "\r{label} in {countdown} (checking again in {next}s)        ",
+            countdown = format_countdown(until - Utc::now().naive_utc()),
+            next = remaining.as_secs(),
+        );
+        ffi::flush();
+        tokio::time::sleep(remaining.min(Duration::from_secs(1))).await;
+    }
+}
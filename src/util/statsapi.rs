@@ -4,14 +4,16 @@ use crate::util::stat::HittingStat;
 use anyhow::{Context, Result};
 use core::fmt::{Debug, Display, Formatter};
 use fxhash::FxHashMap;
-use mlb_api::game::{BattingOrderIndex, TeamWithGameData};
-use mlb_api::meta::GameType;
+use mlb_api::game::{Base, BattingOrderIndex, Play, PlayEvent, TeamWithGameData};
+use mlb_api::meta::{EventType, GameType};
 use mlb_api::person::{Ballplayer, PersonId};
 use mlb_api::season::SeasonId;
+use mlb_api::stats::CountingStat;
 use mlb_api::team::TeamName;
 use mlb_api::{single_stat, HomeAway, TeamSide};
 use pollster::FutureExt;
 use std::cmp::Ordering;
+use std::ops::AddAssign;
 
 #[derive(Clone)]
 pub struct ScoredRunner {
@@ -110,12 +112,223 @@ impl BoldingDisplayKind {
     }
 }
 
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Win,
+    Loss,
+    Tie,
+}
+
+impl Outcome {
+    pub fn from_runs(ours: usize, theirs: usize) -> Self {
+        match ours.cmp(&theirs) {
+            Ordering::Greater => Self::Win,
+            Ordering::Less => Self::Loss,
+            Ordering::Equal => Self::Tie,
+        }
+    }
+}
+
+/// What a scoring play did to the game, from the batting team's perspective
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum ScoreChange {
+    GameTying,
+    GoAhead,
+    LeadChange,
+    Insurance,
+}
+
+impl ScoreChange {
+    /// `previous` is the score before the play. `None` if the batting team is still behind
+    pub fn classify(previous: HomeAway<usize>, score: &Score, batting: TeamSide) -> Option<Self> {
+        let scored = HomeAway::new(score.home_runs, score.away_runs);
+        let (after, fielding) = (scored.choose(batting), scored.choose(!batting));
+        let before = previous.choose(batting);
+        match (before.cmp(&fielding), after.cmp(&fielding)) {
+            (Ordering::Greater, _) => Some(Self::Insurance),
+            (Ordering::Equal, Ordering::Greater) => Some(Self::GoAhead),
+            (Ordering::Less, Ordering::Greater) => Some(Self::LeadChange),
+            (Ordering::Less, Ordering::Equal) => Some(Self::GameTying),
+            _ => None,
+        }
+    }
+}
+
+impl Display for ScoreChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::GameTying => "Game-tying",
+            Self::GoAhead => "Go-ahead",
+            Self::LeadChange => "Lead change",
+            Self::Insurance => "Insurance",
+        })
+    }
+}
+
+/// Counts how many times the lead switched teams; a tie in between doesn't reset who led last.
+#[derive(Default)]
+pub struct LeadChanges {
+    leader: Option<TeamSide>,
+    count: usize,
+    away_runs: usize,
+    home_runs: usize,
+}
+
+impl LeadChanges {
+    pub fn update(&mut self, away_runs: usize, home_runs: usize) {
+        self.away_runs = away_runs;
+        self.home_runs = home_runs;
+        let leader = match away_runs.cmp(&home_runs) {
+            Ordering::Greater => TeamSide::Away,
+            Ordering::Less => TeamSide::Home,
+            Ordering::Equal => return,
+        };
+        if self.leader.is_some_and(|previous| previous != leader) {
+            self.count += 1;
+        }
+        self.leader = Some(leader);
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// As of the last update, so before the scoring play being handled
+    pub fn score(&self) -> HomeAway<usize> {
+        HomeAway::new(self.home_runs, self.away_runs)
+    }
+}
+
+/// A batter's, pitcher's or pitching staff's counting stats for the game, added up one play at a time.
+///
+/// Counted from the plays rather than read from the boxscore, since the boxscore is only current for the latest play and plays replayed after a reconnect would see the final totals.
+#[derive(Copy, Clone, Default)]
+pub struct GameLine {
+    pub plate_appearances: CountingStat,
+    pub pitches: CountingStat,
+    pub at_bats: CountingStat,
+    pub hits: CountingStat,
+    pub doubles: CountingStat,
+    pub triples: CountingStat,
+    pub home_runs: CountingStat,
+    pub walks: CountingStat,
+    /// Walks, hit batters and catcher's interference
+    pub free_passes: CountingStat,
+    pub strikeouts: CountingStat,
+    /// Batters who reached without a hit, which ends a perfect game
+    pub reached_without_hit: CountingStat,
+}
+
+impl GameLine {
+    pub fn from_play(play: &Play) -> Self {
+        let mut line = Self {
+            plate_appearances: 1,
+            pitches: play.play_events.iter().filter(|event| matches!(event, PlayEvent::Pitch { .. })).count() as CountingStat,
+            ..Self::default()
+        };
+        let Some(details) = &play.result.completed_play_details else { return line };
+        match details.event {
+            EventType::Single => line.hits += 1,
+            EventType::Double => {
+                line.hits += 1;
+                line.doubles += 1;
+            },
+            EventType::Triple => {
+                line.hits += 1;
+                line.triples += 1;
+            },
+            EventType::HomeRun => {
+                line.hits += 1;
+                line.home_runs += 1;
+            },
+            EventType::Walk | EventType::IntentionalWalk => {
+                line.walks += 1;
+                line.free_passes += 1;
+            },
+            EventType::HitByPitch | EventType::CatchersInterference => line.free_passes += 1,
+            EventType::Strikeout | EventType::StrikeoutDoublePlay => line.strikeouts += 1,
+            _ => {},
+        }
+        if line.hits > 0 || matches!(details.event,
+            EventType::FieldOut
+            | EventType::ForceOut
+            | EventType::FieldersChoice
+            | EventType::FieldersChoiceFieldOut
+            | EventType::FieldError
+            | EventType::GroundedIntoDoublePlay
+            | EventType::DoublePlay
+            | EventType::TriplePlay
+            | EventType::Strikeout
+            | EventType::StrikeoutDoublePlay
+        ) {
+            line.at_bats += 1;
+        }
+        // from the runners rather than the event, since a dropped third strike is still a strikeout
+        let batter_reached = play.runners.iter().any(|runner| runner.details.runner.id == play.matchup.batter.id && runner.movement.origin_base.is_none() && runner.movement.end_base.is_some());
+        if batter_reached && line.hits == 0 {
+            line.reached_without_hit += 1;
+        }
+        line
+    }
+}
+
+impl AddAssign for GameLine {
+    fn add_assign(&mut self, rhs: Self) {
+        self.plate_appearances += rhs.plate_appearances;
+        self.pitches += rhs.pitches;
+        self.at_bats += rhs.at_bats;
+        self.hits += rhs.hits;
+        self.doubles += rhs.doubles;
+        self.triples += rhs.triples;
+        self.home_runs += rhs.home_runs;
+        self.walks += rhs.walks;
+        self.free_passes += rhs.free_passes;
+        self.strikeouts += rhs.strikeouts;
+        self.reached_without_hit += rhs.reached_without_hit;
+    }
+}
+
+/// The current half inning so far, shared by the posts that look back on it once it ends
+#[derive(Default)]
+pub struct HalfInning {
+    pitcher: Option<PersonId>,
+    /// A pitching change means it can't be one pitcher's immaculate inning
+    had_pitching_change: bool,
+    line: GameLine,
+}
+
+impl HalfInning {
+    pub fn add(&mut self, play: &Play) {
+        let id = play.matchup.pitcher.id;
+        if self.pitcher.is_some_and(|previous| previous != id) {
+            self.had_pitching_change = true;
+        }
+        self.pitcher = Some(id);
+        self.line += GameLine::from_play(play);
+    }
+
+    pub fn plate_appearances(&self) -> CountingStat {
+        self.line.plate_appearances
+    }
+
+    pub fn free_passes(&self) -> CountingStat {
+        self.line.free_passes
+    }
+
+    /// Three strikeouts on nine pitches by one pitcher, so every pitch was a strike
+    pub fn is_immaculate(&self) -> bool {
+        !self.had_pitching_change && self.line.plate_appearances == 3 && self.line.strikeouts == 3 && self.line.pitches == 9
+    }
+}
+
 #[derive(Clone)]
 pub struct Score {
     pub away_abbreviation: String,
     pub away_runs: usize,
     pub home_abbreviation: String,
     pub home_runs: usize,
+    /// Only used when this is a record rather than a game score
+    pub ties: usize,
     pub innings: u8,
     pub who_scored: TeamSide,
     pub runs_bolding: BoldingDisplayKind,
@@ -136,6 +349,7 @@ impl Score {
             away_runs,
             home_abbreviation,
             home_runs,
+            ties: 0,
             innings,
             who_scored,
             runs_bolding,
@@ -161,18 +375,55 @@ impl Display for CodeBlock<'_> {
 
 impl Debug for Score {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let Self { away_abbreviation, away_runs, home_abbreviation, home_runs, innings, who_scored, runs_bolding, team_bolding } = self;
+        let Self { away_abbreviation, away_runs, home_abbreviation, home_runs, ties, innings, who_scored, runs_bolding, team_bolding } = self;
         let (away_abbreviation_bold, home_abbreviation_bold) = team_bolding.bolding(*away_runs, *home_runs, *who_scored);
         let (away_score_bold, home_score_bold) = runs_bolding.bolding(*away_runs, *home_runs, *who_scored);
         let extra_innings_suffix = if *innings > 9 { format!(" ({innings})") } else { String::new() };
-        write!(f, "{away_abbreviation_bold}{away_abbreviation}{away_abbreviation_bold} {away_score_bold}{away_runs}{away_score_bold}-{home_score_bold}{home_runs}{home_score_bold} {home_abbreviation_bold}{home_abbreviation}{home_abbreviation_bold}{extra_innings_suffix}")
+        let ties = if *ties > 0 { format!("-{ties}") } else { String::new() };
+        write!(f, "{away_abbreviation_bold}{away_abbreviation}{away_abbreviation_bold} {away_score_bold}{away_runs}{away_score_bold}-{home_score_bold}{home_runs}{home_score_bold}{ties} {home_abbreviation_bold}{home_abbreviation}{home_abbreviation_bold}{extra_innings_suffix}")
     }
 }
 
 impl Display for Score {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let Self { away_abbreviation, away_runs, home_abbreviation, home_runs, .. } = self;
-        write!(f, "{away_abbreviation} {away_runs}-{home_runs} {home_abbreviation}")
+        let Self { away_abbreviation, away_runs, home_abbreviation, home_runs, ties, .. } = self;
+        let ties = if *ties > 0 { format!("-{ties}") } else { String::new() };
+        write!(f, "{away_abbreviation} {away_runs}-{home_runs}{ties} {home_abbreviation}")
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct BaseOutState {
+    first: bool,
+    second: bool,
+    third: bool,
+    outs: u8,
+}
+
+impl BaseOutState {
+    /// `outs` as of the event in the play, the runners are the ones on base when the plate appearance began
+    pub fn from_play(play: &Play, outs: u8) -> Self {
+        let is_occupied = |base: Base| play.runners.iter().any(|runner| runner.movement.origin_base == Some(base));
+        Self {
+            first: is_occupied(Base::First),
+            second: is_occupied(Base::Second),
+            third: is_occupied(Base::Third),
+            outs,
+        }
+    }
+}
+
+impl Display for BaseOutState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Self { first, second, third, outs } = *self;
+        write!(f, "**{outs}** out{out_suffix}, ", out_suffix = if outs == 1 { "" } else { "s" })?;
+        let runners = [(first, "first"), (second, "second"), (third, "third")].into_iter().filter_map(|(on, base)| on.then_some(base)).collect::<Vec<_>>();
+        match runners.as_slice() {
+            [] => write!(f, "bases empty"),
+            [base] => write!(f, "runner on {base}"),
+            [a, b] => write!(f, "runners on {a} and {b}"),
+            _ => write!(f, "bases loaded"),
+        }
     }
 }
 